@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+// Test fixture only, anyone can create a pool at any rate
+#[cfg(feature = "production")]
+compile_error!("mock-amm is a localnet test fixture and must not be built for production");
+
+declare_id!("7jP43xXjXegXWLueHjzmbWoAxS1posEVfavPzgXMsKnL");
+
+// Local stand-in for a real AMM. Swaps SOL for tokens at a fixed rate out of a pre-funded pool.
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        rate_numerator: u64,
+        rate_denominator: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let bump = *ctx.bumps.get("pool").unwrap();
+        
+        if rate_numerator == 0 || rate_denominator == 0 {
+            return Err(error!(MockAmmError::InvalidRate));
+        }
+        
+        pool.mint = ctx.accounts.mint.key();
+        pool.rate_numerator = rate_numerator;
+        pool.rate_denominator = rate_denominator;
+        pool.bump = bump;
+        
+        Ok(())
+    }
+
+    pub fn swap_sol_for_token(
+        ctx: Context<SwapSolForToken>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        
+        // Tokens out at the pool's fixed rate
+        let amount_out = ((amount_in as u128 * pool.rate_numerator as u128)
+            / pool.rate_denominator as u128) as u64;
+        
+        if amount_out < min_amount_out {
+            return Err(error!(MockAmmError::SlippageExceeded));
+        }
+        
+        // Take SOL from the payer
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.payer.key(),
+                &pool.key(),
+                amount_in,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                pool.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        // Pay out tokens from the pool
+        let mint = pool.mint;
+        let seeds = &[b"pool".as_ref(), mint.as_ref(), &[pool.bump]];
+        let signer = &[&seeds[..]];
+        
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_token_account.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer,
+            ),
+            amount_out,
+        )?;
+        
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 8 + 8 + 1,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"pool-tokens", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SwapSolForToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool-tokens", pool.mint.as_ref()],
+        bump,
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.mint == pool.mint @ MockAmmError::InvalidMint,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+pub struct Pool {
+    pub mint: Pubkey,            // Token paid out by the pool
+    pub rate_numerator: u64,     // Tokens out per `rate_denominator` lamports in
+    pub rate_denominator: u64,
+    pub bump: u8,                // PDA bump
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Invalid rate")]
+    InvalidRate,
+    
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+    
+    #[msg("Invalid mint")]
+    InvalidMint,
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::errors::*;
+
+// Signed oracle payloads are checked by the native ed25519 program. The relayer
+// puts an ed25519 verify instruction right before ours, and we check through the
+// instructions sysvar that it verified `signer` over exactly `message`.
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+// Offsets in the ed25519 instruction that point into its own data
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+pub fn verify_signed_payload(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(error!(ErrorCode::InvalidSignature));
+    }
+    
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    if ix.program_id != ed25519_program::ID || !ix.accounts.is_empty() {
+        return Err(error!(ErrorCode::InvalidSignature));
+    }
+    
+    let data = &ix.data;
+    
+    // Exactly one signature
+    if data.len() < SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+        return Err(error!(ErrorCode::InvalidSignature));
+    }
+    
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_instruction_index = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_instruction_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_instruction_index = read_u16(offsets + 12);
+    
+    // Everything has to come from the verify instruction itself
+    if signature_instruction_index != CURRENT_INSTRUCTION
+        || public_key_instruction_index != CURRENT_INSTRUCTION
+        || message_instruction_index != CURRENT_INSTRUCTION
+    {
+        return Err(error!(ErrorCode::InvalidSignature));
+    }
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + PUBKEY_SIZE)
+        .ok_or(error!(ErrorCode::InvalidSignature))?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(error!(ErrorCode::InvalidSignature))?;
+    
+    if public_key != signer.as_ref() || signed_message != message {
+        return Err(error!(ErrorCode::InvalidSignature));
+    }
+    
+    Ok(())
+}
@@ -40,4 +40,211 @@ pub enum ErrorCode {
     
     #[msg("Exceeds withdrawal limit")]
     ExceedsWithdrawalLimit,
+    
+    #[msg("Invalid stake accounts")]
+    InvalidStakeAccounts,
+    
+    #[msg("Auto-compound not enabled for this stake")]
+    AutoCompoundDisabled,
+    
+    #[msg("Vesting schedule account required")]
+    VestingScheduleRequired,
+    
+    #[msg("Nothing vested to withdraw")]
+    NothingVested,
+    
+    #[msg("Invalid gameweek")]
+    InvalidGameweek,
+    
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+    
+    #[msg("Referral stats account required")]
+    ReferralStatsRequired,
+    
+    #[msg("Treasury vault holds less than the recorded fees")]
+    TreasuryOutOfBalance,
+    
+    #[msg("Treasury withdrawals require multisig approval")]
+    MultisigRequired,
+    
+    #[msg("Invalid multisig configuration")]
+    InvalidMultisigConfig,
+    
+    #[msg("Signer is not part of the multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Proposal already approved by this signer")]
+    AlreadyApproved,
+    
+    #[msg("Proposal approval threshold not met")]
+    ThresholdNotMet,
+    
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+    
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal is stale after a signer set change")]
+    StaleProposal,
+    
+    #[msg("Invalid stake parameter")]
+    InvalidStakeParameter,
+    
+    #[msg("Invalid timelock parameter")]
+    InvalidTimelockParameter,
+    
+    #[msg("Timelock delay has not passed")]
+    TimelockNotReady,
+    
+    #[msg("Queued action has expired")]
+    ActionExpired,
+    
+    #[msg("Queued action already executed")]
+    ActionAlreadyExecuted,
+    
+    #[msg("Withdrawal must go through the timelock")]
+    RequiresTimelock,
+    
+    #[msg("Treasury outflow limit exceeded")]
+    OutflowLimitExceeded,
+    
+    #[msg("Invalid budget parameter")]
+    InvalidBudgetParameter,
+    
+    #[msg("Exceeds budget allocation")]
+    ExceedsBudget,
+    
+    #[msg("Stream has been cancelled")]
+    StreamCancelled,
+    
+    #[msg("Token accounts required for SPL treasury operations")]
+    TokenAccountsRequired,
+    
+    #[msg("Invalid treasury mint")]
+    InvalidTreasuryMint,
+    
+    #[msg("Unsupported swap program")]
+    UnsupportedSwapProgram,
+    
+    #[msg("Buyback epoch cap exceeded")]
+    BuybackCapExceeded,
+    
+    #[msg("Buyback config account required")]
+    BuybackConfigRequired,
+    
+    #[msg("Swap returned less than the minimum amount")]
+    SlippageExceeded,
+    
+    #[msg("This part of the protocol is paused")]
+    ProtocolPaused,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Invalid market parameter")]
+    InvalidMarketParameter,
+    
+    #[msg("Market is closed")]
+    MarketClosed,
+    
+    #[msg("Market has not closed yet")]
+    MarketNotClosed,
+    
+    #[msg("Market already resolved")]
+    MarketAlreadyResolved,
+    
+    #[msg("Market not resolved")]
+    MarketNotResolved,
+    
+    #[msg("Invalid outcome")]
+    InvalidOutcome,
+    
+    #[msg("Invalid position amount")]
+    InvalidPositionAmount,
+    
+    #[msg("Already claimed")]
+    AlreadyClaimed,
+    
+    #[msg("No winnings to claim")]
+    NoWinnings,
+    
+    #[msg("Not supported by this market's mechanism")]
+    WrongMarketMechanism,
+    
+    #[msg("Market already funded")]
+    MarketAlreadyFunded,
+    
+    #[msg("Market not funded")]
+    MarketNotFunded,
+    
+    #[msg("Insufficient shares")]
+    InsufficientShares,
+    
+    #[msg("Outcome token mints not initialized")]
+    OutcomeMintsMissing,
+    
+    #[msg("Market must be resolved through a different instruction")]
+    WrongResolutionPath,
+    
+    #[msg("Gameweek not finalized")]
+    GameweekNotFinalized,
+    
+    #[msg("Gameweek already finalized")]
+    GameweekFinalized,
+    
+    #[msg("Matching player points account required")]
+    PlayerPointsRequired,
+    
+    #[msg("Bond too small")]
+    InsufficientBond,
+    
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Dispute window still open")]
+    DisputeWindowOpen,
+    
+    #[msg("Resolution already disputed or finalized")]
+    AlreadyDisputed,
+    
+    #[msg("Resolution is not disputed")]
+    NotDisputed,
+    
+    #[msg("Missing or invalid oracle signature")]
+    InvalidSignature,
+    
+    #[msg("Invalid fixture data")]
+    InvalidFixture,
+    
+    #[msg("Fixture update is older than the current state")]
+    StaleFixtureUpdate,
+    
+    #[msg("Stake too small to create a market")]
+    InsufficientCreatorStake,
+    
+    #[msg("Position exceeds the market's per-wallet limit")]
+    PositionLimitExceeded,
+    
+    #[msg("Position exceeds the market's outcome pool share cap")]
+    PoolShareExceeded,
+    
+    #[msg("FPL registration too recent for this market")]
+    RegistrationTooRecent,
+    
+    #[msg("Rewards can't be compounded while vesting is enabled")]
+    CompoundingWhileVesting,
+    
+    #[msg("No recorded entry for the finalized gameweek")]
+    NoGameweekEntry,
+    
+    #[msg("Approval does not match the requested action")]
+    ApprovalMismatch,
+    
+    #[msg("Proposal has been cancelled")]
+    ProposalCancelled,
+    
+    #[msg("Council member already voted on this dispute")]
+    AlreadyVoted,
 }
\ No newline at end of file
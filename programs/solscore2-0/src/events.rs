@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::protocol::PauseFlags;
+
+#[event]
+pub struct RewardClaimed {
+    pub user: Pubkey,
+    pub gross_amount: u64,
+    pub fee_amount: u64,
+    pub net_amount: u64,
+    pub vested: bool,
+}
+
+#[event]
+pub struct BuybackExecuted {
+    pub sol_spent: u64,
+    pub tokens_burned: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub paused: PauseFlags,
+}
+
+#[event]
+pub struct MarketResolved {
+    pub market: Pubkey,
+    pub winning_outcome: u8,
+    pub total_pool: u64,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct WinningsClaimed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub stake: u64,
+    pub winnings: u64,
+    pub refund: bool,
+}
+
+#[event]
+pub struct ResolutionProposed {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub outcome: u8,
+    pub bond: u64,
+    pub dispute_deadline: i64,
+}
+
+#[event]
+pub struct ResolutionDisputed {
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub outcome: u8,
+    pub bond: u64,
+}
+
+#[event]
+pub struct MarketVoided {
+    pub market: Pubkey,
+    pub auto_void: bool,
+    pub delisted: bool,
+}
\ No newline at end of file
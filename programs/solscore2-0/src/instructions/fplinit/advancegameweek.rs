@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct AdvanceGameweek<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+}
+
+pub fn advance_gameweek(ctx: Context<AdvanceGameweek>, gameweek: u8, deadline: i64) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    // Gameweeks advance one at a time, each deadline after the last
+    if gameweek != global_state.current_gameweek + 1 || deadline <= global_state.gameweek_deadline {
+        return Err(error!(ErrorCode::InvalidGameweek));
+    }
+    
+    global_state.current_gameweek = gameweek;
+    global_state.gameweek_deadline = deadline;
+    
+    Ok(())
+}
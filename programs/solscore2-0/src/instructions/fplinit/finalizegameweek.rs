@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct FinalizeGameweek<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+}
+
+pub fn finalize_gameweek(ctx: Context<FinalizeGameweek>, gameweek: u8) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    // Gameweeks are finalized in order and never ahead of the current one
+    if gameweek != global_state.finalized_gameweek + 1 || gameweek > global_state.current_gameweek {
+        return Err(error!(ErrorCode::InvalidGameweek));
+    }
+    
+    global_state.finalized_gameweek = gameweek;
+    
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::errors::*;
 
 pub struct FplGlobalParams {
     pub current_gameweek: u8,
+    pub gameweek_deadline: i64,
     pub season_start: i64,
     pub season_end: i64, 
     pub api_url: String,
@@ -19,7 +20,7 @@ pub struct InitializeFplGlobal<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 1 + 8 + 8 + 100 + 1, // Adjust space calculation as needed
+        space = 8 + 32 + 1 + 1 + 8 + 8 + 8 + 100 + 1, // Adjust space calculation as needed
         seeds = [b"fpl-global"],
         bump
     )]
@@ -34,6 +35,8 @@ pub fn initialize_fpl_global(ctx: Context<InitializeFplGlobal>, params: FplGloba
     
     global_state.admin = ctx.accounts.admin.key();
     global_state.current_gameweek = params.current_gameweek;
+    global_state.finalized_gameweek = 0;
+    global_state.gameweek_deadline = params.gameweek_deadline;
     global_state.season_start = params.season_start;
     global_state.season_end = params.season_end;
     global_state.api_url = params.api_url;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+// Admin records a user's final score for a gameweek, the entry streaks count
+#[derive(Accounts)]
+pub struct RecordGameweekScore<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"fpl-user", fpl_user.authority.as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+}
+
+pub fn record_gameweek_score(ctx: Context<RecordGameweekScore>, gameweek: u8, score: u32) -> Result<()> {
+    let fpl_user = &mut ctx.accounts.fpl_user;
+    
+    // One score per gameweek, in order and never ahead of the current one
+    if gameweek == 0
+        || gameweek <= fpl_user.last_entry_gameweek
+        || gameweek > ctx.accounts.global_state.current_gameweek
+    {
+        return Err(error!(ErrorCode::InvalidGameweek));
+    }
+    
+    fpl_user.weekly_score = score;
+    fpl_user.total_score += score;
+    fpl_user.last_entry_gameweek = gameweek;
+    fpl_user.last_updated = Clock::get()?.unix_timestamp;
+    
+    Ok(())
+}
\ No newline at end of file
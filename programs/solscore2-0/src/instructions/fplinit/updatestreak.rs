@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+// Permissionless, anyone can bring a user's streak up to the latest finalized gameweek
+#[derive(Accounts)]
+pub struct UpdateStreak<'info> {
+    #[account(
+        mut,
+        seeds = [b"fpl-user", fpl_user.authority.as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+}
+
+pub fn update_streak(ctx: Context<UpdateStreak>) -> Result<()> {
+    let fpl_user = &mut ctx.accounts.fpl_user;
+    let finalized_gameweek = ctx.accounts.global_state.finalized_gameweek;
+    
+    // Already counted
+    if finalized_gameweek == 0 || fpl_user.last_streak_gameweek >= finalized_gameweek {
+        return Err(error!(ErrorCode::InvalidGameweek));
+    }
+    
+    // Only a gameweek the user was staked through and has a score for counts
+    if fpl_user.active_stakes == 0
+        || fpl_user.staked_since_gameweek > finalized_gameweek
+        || fpl_user.last_entry_gameweek != finalized_gameweek
+    {
+        return Err(error!(ErrorCode::NoGameweekEntry));
+    }
+    
+    // The streak only grows gameweek by gameweek, a gap starts it again
+    if fpl_user.last_streak_gameweek + 1 == finalized_gameweek {
+        fpl_user.streak += 1;
+    } else {
+        fpl_user.streak = 1;
+    }
+    
+    fpl_user.last_streak_gameweek = finalized_gameweek;
+    
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::fpl::*;
+use crate::state::reward::*;
 use crate::errors::*;
 
 
@@ -11,17 +12,26 @@ pub struct RegisterFplUser<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 + 32 + 50 + 200 + 4 + 4 + 8 + 1, // Adjust space calculation as needed
+        space = 8 + 32 + 50 + 200 + 4 + 4 + 8 + 4 + 2 + 1 + 1 + 1 + 33 + 8 + 1, // Adjust space calculation as needed
         seeds = [b"fpl-user", user.key().as_ref()],
         bump
     )]
     pub fpl_user: Account<'info, FplUser>,
     
     pub global_state: Account<'info, FplGlobalState>,
+    
+    // Required when registering with a referrer
+    #[account(
+        mut,
+        seeds = [b"referral", referral_stats.referrer.as_ref()],
+        bump = referral_stats.bump,
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn register_fpl_user(ctx: Context<RegisterFplUser>, fpl_id: String) -> Result<()> {
+pub fn register_fpl_user(ctx: Context<RegisterFplUser>, fpl_id: String, referrer: Option<Pubkey>) -> Result<()> {
     let fpl_user = &mut ctx.accounts.fpl_user;
     let bump = *ctx.bumps.get("fpl_user").unwrap();
     
@@ -30,12 +40,34 @@ pub fn register_fpl_user(ctx: Context<RegisterFplUser>, fpl_id: String) -> Resul
         return Err(error!(ErrorCode::InvalidFplId));
     }
     
+    // Referrer must have opted in with a stats account and can't be the user
+    if let Some(referrer) = referrer {
+        if referrer == ctx.accounts.user.key() {
+            return Err(error!(ErrorCode::InvalidReferrer));
+        }
+        
+        let referral_stats = ctx.accounts.referral_stats
+            .as_mut()
+            .ok_or(error!(ErrorCode::ReferralStatsRequired))?;
+        if referral_stats.referrer != referrer {
+            return Err(error!(ErrorCode::InvalidReferrer));
+        }
+        referral_stats.referee_count += 1;
+    }
+    
     fpl_user.authority = ctx.accounts.user.key();
     fpl_user.fpl_id = fpl_id;
     fpl_user.team_data = Vec::new(); // Will be populated later via API
     fpl_user.weekly_score = 0;
     fpl_user.total_score = 0;
     fpl_user.last_updated = Clock::get()?.unix_timestamp;
+    fpl_user.active_stakes = 0;
+    fpl_user.streak = 0;
+    fpl_user.last_streak_gameweek = 0;
+    fpl_user.last_entry_gameweek = 0;
+    fpl_user.staked_since_gameweek = 0;
+    fpl_user.referrer = referrer;
+    fpl_user.registered_at = Clock::get()?.unix_timestamp;
     fpl_user.bump = bump;
     
     Ok(())
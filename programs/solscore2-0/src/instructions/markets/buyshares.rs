@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::fpl::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::instructions::market::check_position_limits;
+use crate::math::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct BuyShares<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::Lmsr @ ErrorCode::WrongMarketMechanism,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    
+    // Required when the market sets a minimum registration age
+    #[account(
+        seeds = [b"fpl-user", user.key().as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Option<Account<'info, FplUser>>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn buy_shares(ctx: Context<BuyShares>, outcome: u8, amount: u64, max_cost: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let treasury = &mut ctx.accounts.treasury;
+    let user = &ctx.accounts.user;
+    
+    validate_trade(market, outcome, amount)?;
+    
+    let cost = lmsr_buy_cost(&market.shares, market.liquidity, outcome as usize, amount)
+        .ok_or(error!(ErrorCode::InvalidPositionAmount))?;
+    
+    // Protocol and creator fees are charged on top of the cost
+    let (protocol_fee, _) = treasury.split_protocol_fee(cost);
+    let creator_fee = market.creator_fee(cost);
+    
+    if cost + protocol_fee + creator_fee > max_cost {
+        return Err(error!(ErrorCode::SlippageExceeded));
+    }
+    
+    // Caps apply to the collateral paid in and to the share of the outcome's shares
+    let position_total = position.amounts.iter().sum::<u64>() + cost;
+    let outcome_holding = position.shares[outcome as usize] + amount;
+    let outcome_pool = market.shares[outcome as usize] + amount;
+    check_position_limits(
+        market,
+        ctx.accounts.fpl_user.as_ref(),
+        position_total,
+        &[(outcome_holding, outcome_pool)],
+    )?;
+    
+    // Transfer the cost to the market vault, the creator fee is held there until claimed
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &user.key(),
+            &ctx.accounts.market_vault.key(),
+            cost + creator_fee,
+        ),
+        &[
+            user.to_account_info(),
+            ctx.accounts.market_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &user.key(),
+                &ctx.accounts.treasury_vault.key(),
+                protocol_fee,
+            ),
+            &[
+                user.to_account_info(),
+                ctx.accounts.treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        treasury.credit_fee(protocol_fee);
+    }
+    
+    market.shares[outcome as usize] += amount;
+    market.total_pool += cost;
+    market.cost_basis += cost;
+    market.creator_fees_owed += creator_fee;
+    position.shares[outcome as usize] += amount;
+    position.amounts[outcome as usize] += cost;
+    
+    Ok(())
+}
+
+// Helper function to check an LMSR market is tradable
+pub(crate) fn validate_trade(market: &Market, outcome: u8, amount: u64) -> Result<()> {
+    if market.status != MarketStatus::Open || Clock::get()?.unix_timestamp >= market.close_time {
+        return Err(error!(ErrorCode::MarketClosed));
+    }
+    
+    if market.liquidity == 0 {
+        return Err(error!(ErrorCode::MarketNotFunded));
+    }
+    
+    if outcome as usize >= market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    if amount == 0 {
+        return Err(error!(ErrorCode::InvalidPositionAmount));
+    }
+    
+    Ok(())
+}
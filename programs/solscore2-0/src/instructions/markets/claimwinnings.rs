@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::events::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ClaimWinnings<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Resolved
+            || market.status == MarketStatus::Voided @ ErrorCode::MarketNotResolved,
+        constraint = market.mechanism != MarketMechanism::OutcomeTokens @ ErrorCode::WrongMarketMechanism,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = !position.claimed @ ErrorCode::AlreadyClaimed,
+    )]
+    pub position: Account<'info, Position>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let treasury = &mut ctx.accounts.treasury;
+    let market_vault = &ctx.accounts.market_vault;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let user = &ctx.accounts.user;
+    
+    // Winners get their stake back plus their share of the losing pools.
+    // Voided markets, or markets nobody picked the winner of, refund every
+    // position at cost with no fee.
+    let refund = market.status == MarketStatus::Voided || market.is_refund();
+    let (stake, winnings, remainder) = if refund {
+        (market.void_refund_for(position.amounts.iter().sum::<u64>()), 0, 0)
+    } else {
+        let winning_outcome = market.winning_outcome.ok_or(error!(ErrorCode::MarketNotResolved))? as usize;
+        if market.mechanism == MarketMechanism::Lmsr {
+            // Each winning LMSR share pays out one lamport
+            (position.shares[winning_outcome], 0, 0)
+        } else {
+            let stake = position.amounts[winning_outcome];
+            let winnings = market.winnings_for(stake);
+            let remainder = if stake > 0 { market.record_claim(stake, winnings) } else { 0 };
+            (stake, winnings, remainder)
+        }
+    };
+    
+    if stake == 0 {
+        return Err(error!(ErrorCode::NoWinnings));
+    }
+    
+    position.claimed = true;
+    
+    let market_key = market.key();
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    // The last winner to claim settles the rounding remainder to the treasury
+    if remainder > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &market_vault.key(),
+                &treasury_vault.key(),
+                remainder,
+            ),
+            &[
+                market_vault.to_account_info(),
+                treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+        
+        treasury.credit_fee(remainder);
+    }
+    
+    // Transfer the payout to the user
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &market_vault.key(),
+            &user.key(),
+            stake + winnings,
+        ),
+        &[
+            market_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    emit!(WinningsClaimed {
+        market: market_key,
+        user: user.key(),
+        stake,
+        winnings,
+        refund,
+    });
+    
+    Ok(())
+}
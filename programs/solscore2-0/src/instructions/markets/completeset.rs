@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, MintTo, Token, TokenAccount};
+use crate::state::market::*;
+use crate::state::fpl::*;
+use crate::state::protocol::*;
+use crate::instructions::market::check_position_limits;
+use crate::errors::*;
+
+// Outcome mints and the user's token accounts are passed as remaining accounts,
+// as (mint, token account) pairs in outcome order
+#[derive(Accounts)]
+pub struct CompleteSet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::OutcomeTokens @ ErrorCode::WrongMarketMechanism,
+        constraint = market.has_outcome_mints() @ ErrorCode::OutcomeMintsMissing,
+    )]
+    pub market: Account<'info, Market>,
+    
+    // Required when minting into a market with a minimum registration age
+    #[account(
+        seeds = [b"fpl-user", user.key().as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Option<Account<'info, FplUser>>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Deposit `amount` lamports and receive `amount` of every outcome token
+pub fn mint_complete_set<'info>(
+    ctx: Context<'_, '_, '_, 'info, CompleteSet<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let user = &ctx.accounts.user;
+    
+    if market.status != MarketStatus::Open || Clock::get()?.unix_timestamp >= market.close_time {
+        return Err(error!(ErrorCode::MarketClosed));
+    }
+    
+    if amount == 0 {
+        return Err(error!(ErrorCode::InvalidPositionAmount));
+    }
+    
+    let pairs = outcome_accounts(market, ctx.remaining_accounts)?;
+    
+    // Each outcome token is backed one for one, so the caps apply to the wallet's
+    // largest token balance and its share of every outcome's supply
+    let mut holdings = Vec::with_capacity(pairs.len());
+    for (_, token_info) in pairs.iter() {
+        let token_account = TokenAccount::try_deserialize(&mut &token_info.try_borrow_data()?[..])?;
+        if token_account.owner != user.key() {
+            return Err(error!(ErrorCode::UnauthorizedAccess));
+        }
+        holdings.push((token_account.amount + amount, market.total_pool + amount));
+    }
+    let position_total = holdings.iter().map(|(holding, _)| *holding).max().unwrap_or(amount);
+    check_position_limits(market, ctx.accounts.fpl_user.as_ref(), position_total, &holdings)?;
+    
+    // Transfer collateral to the market vault
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &user.key(),
+            &ctx.accounts.market_vault.key(),
+            amount,
+        ),
+        &[
+            user.to_account_info(),
+            ctx.accounts.market_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    
+    // The market PDA is the mint authority for its outcome tokens
+    let market_id = market.id.to_le_bytes();
+    let seeds = &[b"market".as_ref(), market_id.as_ref(), &[market.bump]];
+    let signer = &[&seeds[..]];
+    
+    for (mint_info, token_info) in pairs {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: mint_info.clone(),
+                    to: token_info.clone(),
+                    authority: market.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+    }
+    
+    ctx.accounts.market.total_pool += amount;
+    
+    Ok(())
+}
+
+// Burn `amount` of every outcome token and get `amount` lamports back
+pub fn redeem_complete_set<'info>(
+    ctx: Context<'_, '_, '_, 'info, CompleteSet<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let user = &ctx.accounts.user;
+    let market_vault = &ctx.accounts.market_vault;
+    
+    // After resolution only the winning token is worth anything, see `redeem_winning`
+    if market.status == MarketStatus::Resolved {
+        return Err(error!(ErrorCode::MarketAlreadyResolved));
+    }
+    
+    if amount == 0 || amount > market.total_pool {
+        return Err(error!(ErrorCode::InvalidPositionAmount));
+    }
+    
+    let pairs = outcome_accounts(market, ctx.remaining_accounts)?;
+    
+    for (mint_info, token_info) in pairs {
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: mint_info.clone(),
+                    from: token_info.clone(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    
+    let market_key = market.key();
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &market_vault.key(),
+            &user.key(),
+            amount,
+        ),
+        &[
+            market_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    ctx.accounts.market.total_pool -= amount;
+    
+    Ok(())
+}
+
+// Helper function to pair each outcome mint with the user's token account
+fn outcome_accounts<'a, 'info>(
+    market: &Market,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>> {
+    if remaining_accounts.len() != market.outcome_mints.len() * 2 {
+        return Err(error!(ErrorCode::TokenAccountsRequired));
+    }
+    
+    let mut pairs = Vec::with_capacity(market.outcome_mints.len());
+    for (expected_mint, chunk) in market.outcome_mints.iter().zip(remaining_accounts.chunks(2)) {
+        if chunk[0].key() != *expected_mint {
+            return Err(error!(ErrorCode::InvalidOutcome));
+        }
+        // The token program checks the account's mint and owner
+        pairs.push((&chunk[0], &chunk[1]));
+    }
+    
+    Ok(pairs)
+}
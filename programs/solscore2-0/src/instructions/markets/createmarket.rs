@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::fpl::*;
+use crate::state::oracle::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketParams {
+    pub question: String,
+    pub fixture_id: u64,
+    pub outcomes: Vec<String>,
+    pub mechanism: MarketMechanism,
+    pub close_time: i64,
+    pub resolution_source: String,
+    pub player_condition: Option<PlayerCondition>,
+    pub limits: PositionLimits,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MarketParams)]
+pub struct CreateMarket<'info> {
+    #[account(
+        mut,
+        constraint = market_config.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Market::SPACE, // Adjust space calculation
+        seeds = [b"market", &market_config.market_count.to_le_bytes()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    // Markets are only opened on fixtures the oracle has published
+    #[account(
+        seeds = [b"fixture", &params.fixture_id.to_le_bytes()],
+        bump = fixture.bump,
+    )]
+    pub fixture: Account<'info, Fixture>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_market(ctx: Context<CreateMarket>, params: MarketParams) -> Result<()> {
+    let bump = *ctx.bumps.get("market").unwrap();
+    let admin = ctx.accounts.admin.key();
+    
+    fund_vault_rent(
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.market_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    init_market(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.market_config,
+        &ctx.accounts.global_state,
+        &ctx.accounts.fixture,
+        params,
+        admin,
+        0,
+        0,
+        bump,
+    )
+}
+
+// Helper function to set up a new market, shared by protocol and user markets
+pub(crate) fn init_market(
+    market: &mut Market,
+    market_config: &mut MarketConfig,
+    global_state: &FplGlobalState,
+    fixture: &Fixture,
+    params: MarketParams,
+    creator: Pubkey,
+    creator_fee_bps: u16,
+    creation_bond: u64,
+    bump: u8,
+) -> Result<()> {
+    // Validate parameters
+    validate_market_params(&params, global_state)?;
+    
+    if params.close_time <= Clock::get()?.unix_timestamp {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    // Only scheduled fixtures take new markets, and betting closes by kickoff
+    if fixture.fixture_id != params.fixture_id || fixture.status != FixtureStatus::Scheduled {
+        return Err(error!(ErrorCode::InvalidFixture));
+    }
+    
+    if params.close_time > fixture.kickoff {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    market.id = market_config.market_count;
+    market.creator = creator;
+    market.creator_fee_bps = creator_fee_bps;
+    market.creator_fees_owed = 0;
+    market.creation_bond = creation_bond;
+    market.delisted = false;
+    market.question = params.question;
+    market.fixture_id = params.fixture_id;
+    market.outcome_pools = vec![0; params.outcomes.len()];
+    market.shares = vec![0; params.outcomes.len()];
+    market.outcome_mints = Vec::new();
+    market.mechanism = params.mechanism;
+    market.liquidity = 0;
+    market.subsidy = 0;
+    market.cost_basis = 0;
+    market.outcomes = params.outcomes;
+    market.total_pool = 0;
+    market.fee_amount = 0;
+    market.distributable = 0;
+    market.claimed_stake = 0;
+    market.distributed = 0;
+    market.close_time = params.close_time;
+    market.resolution_source = params.resolution_source;
+    market.player_condition = params.player_condition;
+    market.limits = params.limits;
+    market.status = MarketStatus::Open;
+    market.winning_outcome = None;
+    market.resolved_at = 0;
+    market.bump = bump;
+    
+    market_config.market_count += 1;
+    
+    Ok(())
+}
+
+// Helper function to make the market vault rent exempt up front. The rent stays
+// in the vault for good and is never counted in `total_pool`, so payouts can't
+// drain the vault below the minimum balance.
+pub(crate) fn fund_vault_rent<'info>(
+    payer: &AccountInfo<'info>,
+    market_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_reserve = Rent::get()?.minimum_balance(0).saturating_sub(market_vault.lamports());
+    if rent_reserve == 0 {
+        return Ok(());
+    }
+    
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &payer.key(),
+            &market_vault.key(),
+            rent_reserve,
+        ),
+        &[
+            payer.clone(),
+            market_vault.clone(),
+            system_program.clone(),
+        ],
+    )?;
+    
+    Ok(())
+}
+
+// Helper function to validate market parameters
+fn validate_market_params(params: &MarketParams, global_state: &FplGlobalState) -> Result<()> {
+    if params.question.is_empty()
+        || params.question.len() > MAX_QUESTION_LEN
+        || params.resolution_source.len() > MAX_RESOLUTION_SOURCE_LEN
+    {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    if params.outcomes.len() < MIN_OUTCOMES || params.outcomes.len() > MAX_OUTCOMES {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    if params.outcomes.iter().any(|label| label.is_empty() || label.len() > MAX_OUTCOME_LABEL_LEN) {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    let limits = &params.limits;
+    if limits.max_pool_share_bps > 10_000 || limits.min_registration_age < 0 {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    // Player markets are yes/no questions on the current gameweek, closing by its deadline
+    if let Some(condition) = &params.player_condition {
+        if params.outcomes.len() != 2 {
+            return Err(error!(ErrorCode::InvalidMarketParameter));
+        }
+        let gameweek = condition.gameweek();
+        if gameweek != global_state.current_gameweek || gameweek <= global_state.finalized_gameweek {
+            return Err(error!(ErrorCode::InvalidGameweek));
+        }
+        if params.close_time > global_state.gameweek_deadline {
+            return Err(error!(ErrorCode::InvalidMarketParameter));
+        }
+        if let PlayerCondition::Outscores { player_id, other_player_id, .. } = condition {
+            if player_id == other_player_id {
+                return Err(error!(ErrorCode::InvalidMarketParameter));
+            }
+        }
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::fpl::*;
+use crate::state::oracle::*;
+use crate::state::stake::*;
+use crate::state::protocol::*;
+use crate::instructions::market::{fund_vault_rent, init_market};
+use crate::errors::*;
+
+// Registered FPL users with a large enough stake can open their own parimutuel
+// markets. The creation bond is returned once the market settles, unless the
+// market gets delisted.
+#[derive(Accounts)]
+#[instruction(params: MarketParams)]
+pub struct CreateUserMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"fpl-user", creator.key().as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
+    #[account(
+        constraint = stake.owner == creator.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = stake.is_active @ ErrorCode::StakeNotActive,
+        constraint = stake.amount >= market_config.min_creator_stake @ ErrorCode::InsufficientCreatorStake,
+    )]
+    pub stake: Account<'info, Stake>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = Market::SPACE, // Adjust space calculation
+        seeds = [b"market", &market_config.market_count.to_le_bytes()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    // Markets are only opened on fixtures the oracle has published
+    #[account(
+        seeds = [b"fixture", &params.fixture_id.to_le_bytes()],
+        bump = fixture.bump,
+    )]
+    pub fixture: Account<'info, Fixture>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the creation bond
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_user_market(ctx: Context<CreateUserMarket>, params: MarketParams, creator_fee_bps: u16) -> Result<()> {
+    let creator = &ctx.accounts.creator;
+    let creation_bond = ctx.accounts.market_config.creation_bond;
+    let bump = *ctx.bumps.get("market").unwrap();
+    
+    // User markets can't draw on the treasury or need admin setup
+    if params.mechanism != MarketMechanism::Parimutuel {
+        return Err(error!(ErrorCode::WrongMarketMechanism));
+    }
+    
+    if creator_fee_bps > ctx.accounts.market_config.max_creator_fee_bps {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    // Transfer the creation bond to the bond vault
+    if creation_bond > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &creator.key(),
+                &ctx.accounts.bond_vault.key(),
+                creation_bond,
+            ),
+            &[
+                creator.to_account_info(),
+                ctx.accounts.bond_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+    
+    fund_vault_rent(
+        &creator.to_account_info(),
+        &ctx.accounts.market_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    init_market(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.market_config,
+        &ctx.accounts.global_state,
+        &ctx.accounts.fixture,
+        params,
+        creator.key(),
+        creator_fee_bps,
+        creation_bond,
+        bump,
+    )
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::protocol::*;
+use crate::instructions::market::release_bond;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CreatorClaim<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.creator == creator.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the creation bond
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_creator_fees(ctx: Context<CreatorClaim>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let market_vault = &ctx.accounts.market_vault;
+    let creator = &ctx.accounts.creator;
+    
+    let amount = market.creator_fees_owed;
+    if amount == 0 {
+        return Err(error!(ErrorCode::NoWinnings));
+    }
+    
+    market.creator_fees_owed = 0;
+    
+    let market_key = market.key();
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &market_vault.key(),
+            &creator.key(),
+            amount,
+        ),
+        &[
+            market_vault.to_account_info(),
+            creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
+
+// The creation bond comes back once the market is settled or voided without being delisted
+pub fn reclaim_creation_bond(ctx: Context<CreatorClaim>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    
+    if market.status == MarketStatus::Open {
+        return Err(error!(ErrorCode::MarketNotResolved));
+    }
+    
+    let amount = market.creation_bond;
+    if amount == 0 || market.delisted {
+        return Err(error!(ErrorCode::NoWinnings));
+    }
+    
+    market.creation_bond = 0;
+    
+    let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+    release_bond(
+        market.key(),
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        bond_vault_bump,
+        amount,
+    )
+}
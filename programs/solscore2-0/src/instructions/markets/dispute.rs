@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::protocol::*;
+use crate::events::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"resolution", market.key().as_ref()],
+        bump = resolution.bump,
+        constraint = resolution.status == ResolutionStatus::Proposed @ ErrorCode::AlreadyDisputed,
+    )]
+    pub resolution: Account<'info, ResolutionProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds resolution bonds
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// A dispute must outbid the proposer's bond and escalates the market to the council
+pub fn dispute(ctx: Context<DisputeResolution>, outcome: u8, bond: u64) -> Result<()> {
+    let resolution = &mut ctx.accounts.resolution;
+    let disputer = &ctx.accounts.disputer;
+    
+    if Clock::get()?.unix_timestamp > resolution.dispute_deadline {
+        return Err(error!(ErrorCode::DisputeWindowClosed));
+    }
+    
+    if outcome as usize >= ctx.accounts.market.outcomes.len() || outcome == resolution.proposed_outcome {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    if bond <= resolution.proposer_bond {
+        return Err(error!(ErrorCode::InsufficientBond));
+    }
+    
+    // Transfer the bond to the bond vault
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &disputer.key(),
+            &ctx.accounts.bond_vault.key(),
+            bond,
+        ),
+        &[
+            disputer.to_account_info(),
+            ctx.accounts.bond_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    
+    resolution.disputer = Some(disputer.key());
+    resolution.disputed_outcome = outcome;
+    resolution.disputer_bond = bond;
+    resolution.status = ResolutionStatus::Disputed;
+    
+    emit!(ResolutionDisputed {
+        market: ctx.accounts.market.key(),
+        disputer: disputer.key(),
+        outcome,
+        bond,
+    });
+    
+    Ok(())
+}
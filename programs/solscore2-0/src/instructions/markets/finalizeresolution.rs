@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::instructions::market::{settle_market, release_bond};
+use crate::errors::*;
+
+// Permissionless once the dispute window has passed without a dispute
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketAlreadyResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"resolution", market.key().as_ref()],
+        bump = resolution.bump,
+        constraint = resolution.status == ResolutionStatus::Proposed @ ErrorCode::AlreadyDisputed,
+    )]
+    pub resolution: Account<'info, ResolutionProposal>,
+    
+    #[account(
+        mut,
+        address = resolution.proposer @ ErrorCode::UnauthorizedAccess,
+    )]
+    /// CHECK: Receives the proposer's bond back
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds resolution bonds
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+    let resolution = &mut ctx.accounts.resolution;
+    
+    if Clock::get()?.unix_timestamp <= resolution.dispute_deadline {
+        return Err(error!(ErrorCode::DisputeWindowOpen));
+    }
+    
+    resolution.status = ResolutionStatus::Finalized;
+    
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    settle_market(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.market_vault.to_account_info(),
+        &ctx.accounts.treasury_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        market_vault_bump,
+        resolution.proposed_outcome,
+    )?;
+    
+    // Undisputed proposers get their bond back
+    let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+    release_bond(
+        ctx.accounts.market.key(),
+        &ctx.accounts.bond_vault.to_account_info(),
+        &ctx.accounts.proposer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        bond_vault_bump,
+        resolution.proposer_bond,
+    )
+}
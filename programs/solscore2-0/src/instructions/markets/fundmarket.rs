@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::math::*;
+use crate::errors::*;
+
+// Seeds an LMSR market with its worst case loss, b * ln(n), from the treasury
+#[derive(Accounts)]
+pub struct FundMarket<'info> {
+    #[account(
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = !treasury.multisig_enabled @ ErrorCode::MultisigRequired,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::Lmsr @ ErrorCode::WrongMarketMechanism,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketClosed,
+        constraint = market.liquidity == 0 @ ErrorCode::MarketAlreadyFunded,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_market(ctx: Context<FundMarket>, liquidity: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    
+    if liquidity == 0 {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    let subsidy = lmsr_max_loss(liquidity, market.outcomes.len());
+    
+    // Subsidies are treasury outflows like any other
+    treasury.record_withdrawal(Clock::get()?.unix_timestamp, treasury_vault.lamports(), subsidy)?;
+    
+    let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+    let seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &treasury_vault.key(),
+            &ctx.accounts.market_vault.key(),
+            subsidy,
+        ),
+        &[
+            treasury_vault.to_account_info(),
+            ctx.accounts.market_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    market.liquidity = liquidity;
+    market.subsidy = subsidy;
+    market.total_pool += subsidy;
+    
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketConfigParams {
+    pub resolver: Pubkey,
+    pub council: Vec<Pubkey>,
+    pub council_threshold: u8,
+    pub min_proposal_bond: u64,
+    pub dispute_window: i64,
+    pub auto_void_delay: i64,
+    pub creation_bond: u64,
+    pub min_creator_stake: u64,
+    pub max_creator_fee_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarketConfig<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 32 + (4 + 32 * MAX_COUNCIL_MEMBERS) + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 1, // Adjust space calculation
+        seeds = [b"market-config"],
+        bump
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_market_config(ctx: Context<InitializeMarketConfig>, params: MarketConfigParams) -> Result<()> {
+    let market_config = &mut ctx.accounts.market_config;
+    let bump = *ctx.bumps.get("market_config").unwrap();
+    
+    // Validate parameters
+    if params.min_proposal_bond == 0
+        || params.dispute_window <= 0
+        || params.auto_void_delay <= params.dispute_window
+        || params.max_creator_fee_bps > MAX_CREATOR_FEE_BPS
+    {
+        return Err(error!(ErrorCode::InvalidMarketParameter));
+    }
+    
+    // Disputes are settled by an M-of-N council rather than a single key
+    MarketConfig::validate_council(&params.council, params.council_threshold)?;
+    
+    market_config.admin = ctx.accounts.admin.key();
+    market_config.resolver = params.resolver;
+    market_config.council = params.council;
+    market_config.council_threshold = params.council_threshold;
+    market_config.market_count = 0;
+    market_config.min_proposal_bond = params.min_proposal_bond;
+    market_config.dispute_window = params.dispute_window;
+    market_config.auto_void_delay = params.auto_void_delay;
+    market_config.creation_bond = params.creation_bond;
+    market_config.min_creator_stake = params.min_creator_stake;
+    market_config.max_creator_fee_bps = params.max_creator_fee_bps;
+    market_config.bump = bump;
+    
+    Ok(())
+}
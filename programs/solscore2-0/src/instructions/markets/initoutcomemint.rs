@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::market::*;
+use crate::errors::*;
+
+// Share tokens use the same decimals as SOL, one token is backed by one SOL
+pub const OUTCOME_TOKEN_DECIMALS: u8 = 9;
+
+// Outcome mints are created one per call, in outcome order
+#[derive(Accounts)]
+#[instruction(outcome: u8)]
+pub struct InitializeOutcomeMint<'info> {
+    #[account(
+        mut,
+        constraint = market_config.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::OutcomeTokens @ ErrorCode::WrongMarketMechanism,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketClosed,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"outcome-mint", market.key().as_ref(), &[outcome]],
+        bump,
+        mint::decimals = OUTCOME_TOKEN_DECIMALS,
+        mint::authority = market,
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_outcome_mint(ctx: Context<InitializeOutcomeMint>, outcome: u8) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    
+    if outcome as usize != market.outcome_mints.len() || outcome as usize >= market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    market.outcome_mints.push(ctx.accounts.outcome_mint.key());
+    
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::math::*;
+use crate::errors::*;
+
+// Permissionless view, current LMSR prices scaled by 1e12
+#[derive(Accounts)]
+pub struct MarketPrices<'info> {
+    #[account(
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::Lmsr @ ErrorCode::WrongMarketMechanism,
+    )]
+    pub market: Account<'info, Market>,
+}
+
+pub fn market_prices(ctx: Context<MarketPrices>) -> Result<Vec<u64>> {
+    let market = &ctx.accounts.market;
+    
+    if market.liquidity == 0 {
+        return Err(error!(ErrorCode::MarketNotFunded));
+    }
+    
+    Ok(lmsr_prices(&market.shares, market.liquidity))
+}
@@ -0,0 +1,43 @@
+pub mod initmarketconfig;
+pub mod createmarket;
+pub mod openposition;
+pub mod placeposition;
+pub mod settlemarket;
+pub mod claimwinnings;
+pub mod fundmarket;
+pub mod buyshares;
+pub mod sellshares;
+pub mod marketprices;
+pub mod initoutcomemint;
+pub mod completeset;
+pub mod redeemwinning;
+pub mod resolveplayermarket;
+pub mod proposeresolution;
+pub mod dispute;
+pub mod finalizeresolution;
+pub mod resolvedispute;
+pub mod voidmarket;
+pub mod createusermarket;
+pub mod creatorclaims;
+
+pub use initmarketconfig::*;
+pub use createmarket::*;
+pub use openposition::*;
+pub use placeposition::*;
+pub use settlemarket::*;
+pub use claimwinnings::*;
+pub use fundmarket::*;
+pub use buyshares::*;
+pub use sellshares::*;
+pub use marketprices::*;
+pub use initoutcomemint::*;
+pub use completeset::*;
+pub use redeemwinning::*;
+pub use resolveplayermarket::*;
+pub use proposeresolution::*;
+pub use dispute::*;
+pub use finalizeresolution::*;
+pub use resolvedispute::*;
+pub use voidmarket::*;
+pub use createusermarket::*;
+pub use creatorclaims::*;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 32 + 32 + (4 + MAX_OUTCOMES * 8) + (4 + MAX_OUTCOMES * 8) + 1 + 1, // Adjust space calculation
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let bump = *ctx.bumps.get("position").unwrap();
+    
+    position.market = ctx.accounts.market.key();
+    position.owner = ctx.accounts.user.key();
+    position.amounts = vec![0; ctx.accounts.market.outcomes.len()];
+    position.shares = vec![0; ctx.accounts.market.outcomes.len()];
+    position.claimed = false;
+    position.bump = bump;
+    
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::fpl::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct PlacePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    
+    // Required when the market sets a minimum registration age
+    #[account(
+        seeds = [b"fpl-user", user.key().as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Option<Account<'info, FplUser>>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn place_position(ctx: Context<PlacePosition>, outcome: u8, amount: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let user = &ctx.accounts.user;
+    let market_vault = &ctx.accounts.market_vault;
+    
+    if market.status != MarketStatus::Open || Clock::get()?.unix_timestamp >= market.close_time {
+        return Err(error!(ErrorCode::MarketClosed));
+    }
+    
+    // LMSR markets are traded through `buy_shares` and `sell_shares`
+    if market.mechanism != MarketMechanism::Parimutuel {
+        return Err(error!(ErrorCode::WrongMarketMechanism));
+    }
+    
+    if outcome as usize >= market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    if amount == 0 {
+        return Err(error!(ErrorCode::InvalidPositionAmount));
+    }
+    
+    // Enforce the market's per-wallet caps on the position after this stake
+    let position_total = position.amounts.iter().sum::<u64>() + amount;
+    let outcome_holding = position.amounts[outcome as usize] + amount;
+    let outcome_pool = market.outcome_pools[outcome as usize] + amount;
+    check_position_limits(
+        market,
+        ctx.accounts.fpl_user.as_ref(),
+        position_total,
+        &[(outcome_holding, outcome_pool)],
+    )?;
+    
+    // Transfer collateral to the market vault
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &user.key(),
+            &market_vault.key(),
+            amount,
+        ),
+        &[
+            user.to_account_info(),
+            market_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    
+    market.outcome_pools[outcome as usize] += amount;
+    market.total_pool += amount;
+    position.amounts[outcome as usize] += amount;
+    
+    Ok(())
+}
+
+// Helper function to enforce the market's per-wallet caps after a trade adds exposure,
+// `holdings` pairs the wallet's holding with the outcome total for every outcome added to
+pub(crate) fn check_position_limits(
+    market: &Market,
+    fpl_user: Option<&Account<FplUser>>,
+    position_total: u64,
+    holdings: &[(u64, u64)],
+) -> Result<()> {
+    let limits = market.limits;
+    let registered_at = fpl_user.map(|fpl_user| fpl_user.registered_at);
+    limits.check_registration(registered_at, Clock::get()?.unix_timestamp)?;
+    
+    for &(outcome_holding, outcome_pool) in holdings {
+        limits.check(position_total, outcome_holding, outcome_pool)?;
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::protocol::*;
+use crate::events::*;
+use crate::errors::*;
+
+// Anyone can propose an outcome once the market has closed, backed by a bond
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketAlreadyResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + 32 + 32 + 1 + 8 + (1 + 32) + 1 + 8 + 8 + 1 + (4 + (32 + 1) * MAX_COUNCIL_MEMBERS) + 1, // Adjust space calculation
+        seeds = [b"resolution", market.key().as_ref()],
+        bump
+    )]
+    pub resolution: Account<'info, ResolutionProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds resolution bonds
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_resolution(ctx: Context<ProposeResolution>, outcome: u8, bond: u64) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let market_config = &ctx.accounts.market_config;
+    let resolution = &mut ctx.accounts.resolution;
+    let proposer = &ctx.accounts.proposer;
+    let bump = *ctx.bumps.get("resolution").unwrap();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Player markets resolve from oracle data only, see `resolve_player_market`
+    if market.player_condition.is_some() {
+        return Err(error!(ErrorCode::WrongResolutionPath));
+    }
+    
+    if current_time < market.close_time {
+        return Err(error!(ErrorCode::MarketNotClosed));
+    }
+    
+    if outcome as usize >= market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    if bond < market_config.min_proposal_bond {
+        return Err(error!(ErrorCode::InsufficientBond));
+    }
+    
+    // Transfer the bond to the bond vault
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &proposer.key(),
+            &ctx.accounts.bond_vault.key(),
+            bond,
+        ),
+        &[
+            proposer.to_account_info(),
+            ctx.accounts.bond_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+    
+    resolution.market = market.key();
+    resolution.proposer = proposer.key();
+    resolution.proposed_outcome = outcome;
+    resolution.proposer_bond = bond;
+    resolution.disputer = None;
+    resolution.disputed_outcome = 0;
+    resolution.disputer_bond = 0;
+    resolution.dispute_deadline = current_time + market_config.dispute_window;
+    resolution.status = ResolutionStatus::Proposed;
+    resolution.votes = Vec::new();
+    resolution.bump = bump;
+    
+    emit!(ResolutionProposed {
+        market: market.key(),
+        proposer: proposer.key(),
+        outcome,
+        bond,
+        dispute_deadline: resolution.dispute_deadline,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::market::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct RedeemWinning<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::OutcomeTokens @ ErrorCode::WrongMarketMechanism,
+        constraint = market.status == MarketStatus::Resolved
+            || market.status == MarketStatus::Voided @ ErrorCode::MarketNotResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(mut)]
+    pub winning_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = winning_mint,
+        token::authority = user,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Each winning token is redeemed 1:1 for collateral. In a voided market every
+// outcome token is worth an equal share of one complete set.
+pub fn redeem_winning(ctx: Context<RedeemWinning>, amount: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user = &ctx.accounts.user;
+    let market_vault = &ctx.accounts.market_vault;
+    let mint_key = ctx.accounts.winning_mint.key();
+    
+    let payout = if market.status == MarketStatus::Voided {
+        if !market.outcome_mints.contains(&mint_key) {
+            return Err(error!(ErrorCode::InvalidOutcome));
+        }
+        amount / market.outcomes.len() as u64
+    } else {
+        let winning_outcome = market.winning_outcome.ok_or(error!(ErrorCode::MarketNotResolved))?;
+        if mint_key != market.outcome_mints[winning_outcome as usize] {
+            return Err(error!(ErrorCode::InvalidOutcome));
+        }
+        amount
+    };
+    
+    if payout == 0 {
+        return Err(error!(ErrorCode::InvalidPositionAmount));
+    }
+    
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.winning_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let market_key = market.key();
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &market_vault.key(),
+            &user.key(),
+            payout,
+        ),
+        &[
+            market_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    market.total_pool -= payout;
+    
+    Ok(())
+}
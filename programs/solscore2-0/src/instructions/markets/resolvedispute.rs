@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::instructions::market::{settle_market, release_bond};
+use crate::errors::*;
+
+// Council members vote on a disputed market, it settles once `council_threshold`
+// of them agree on an outcome. Whoever was right takes the other side's bond,
+// if neither was right both bonds go to the treasury.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        constraint = market_config.council.contains(&council_member.key()) @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub council_member: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketAlreadyResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"resolution", market.key().as_ref()],
+        bump = resolution.bump,
+        constraint = resolution.status == ResolutionStatus::Disputed @ ErrorCode::NotDisputed,
+    )]
+    pub resolution: Account<'info, ResolutionProposal>,
+    
+    #[account(
+        mut,
+        address = resolution.proposer @ ErrorCode::UnauthorizedAccess,
+    )]
+    /// CHECK: Receives the bonds if the proposal stands
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = Some(disputer.key()) == resolution.disputer @ ErrorCode::UnauthorizedAccess,
+    )]
+    /// CHECK: Receives the bonds if the dispute succeeds
+    pub disputer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds resolution bonds
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, outcome: u8) -> Result<()> {
+    let market_config = &ctx.accounts.market_config;
+    let resolution = &mut ctx.accounts.resolution;
+    let member = ctx.accounts.council_member.key();
+    
+    if outcome as usize >= ctx.accounts.market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    if resolution.votes.iter().any(|vote| vote.member == member) {
+        return Err(error!(ErrorCode::AlreadyVoted));
+    }
+    
+    resolution.votes.push(CouncilVote { member, outcome });
+    
+    // Wait for enough of the council to agree
+    if resolution.tally(&market_config.council, outcome) < market_config.council_threshold as usize {
+        return Ok(());
+    }
+    
+    resolution.status = ResolutionStatus::Finalized;
+    
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    settle_market(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.market_vault.to_account_info(),
+        &ctx.accounts.treasury_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        market_vault_bump,
+        outcome,
+    )?;
+    
+    let bonds = resolution.proposer_bond + resolution.disputer_bond;
+    let recipient = if outcome == resolution.proposed_outcome {
+        ctx.accounts.proposer.to_account_info()
+    } else if outcome == resolution.disputed_outcome {
+        ctx.accounts.disputer.to_account_info()
+    } else {
+        // Neither side was right, both bonds are slashed to the treasury
+        ctx.accounts.treasury.credit_fee(bonds);
+        ctx.accounts.treasury_vault.to_account_info()
+    };
+    
+    let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+    release_bond(
+        ctx.accounts.market.key(),
+        &ctx.accounts.bond_vault.to_account_info(),
+        &recipient,
+        &ctx.accounts.system_program.to_account_info(),
+        bond_vault_bump,
+        bonds,
+    )
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::oracle::*;
+use crate::state::treasury::*;
+use crate::state::fpl::*;
+use crate::state::protocol::*;
+use crate::instructions::market::settle_market;
+use crate::errors::*;
+
+// Permissionless, the outcome comes straight from the oracle's published points
+#[derive(Accounts)]
+pub struct ResolvePlayerMarket<'info> {
+    pub resolver: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketAlreadyResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        seeds = [b"player-points", &player_points.player_id.to_le_bytes(), &[player_points.gameweek]],
+        bump = player_points.bump,
+    )]
+    pub player_points: Account<'info, PlayerGameweekPoints>,
+    
+    // Required for `Outscores` markets
+    #[account(
+        seeds = [b"player-points", &other_player_points.player_id.to_le_bytes(), &[other_player_points.gameweek]],
+        bump = other_player_points.bump,
+    )]
+    pub other_player_points: Option<Account<'info, PlayerGameweekPoints>>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_player_market(ctx: Context<ResolvePlayerMarket>) -> Result<()> {
+    let condition = ctx.accounts.market.player_condition
+        .ok_or(error!(ErrorCode::WrongResolutionPath))?;
+    let player_points = &ctx.accounts.player_points;
+    
+    // Points are only final once the gameweek is
+    if condition.gameweek() > ctx.accounts.global_state.finalized_gameweek {
+        return Err(error!(ErrorCode::GameweekNotFinalized));
+    }
+    
+    let winning_outcome = match condition {
+        PlayerCondition::PointsAtLeast { player_id, gameweek, .. } => {
+            check_points(player_points, player_id, gameweek)?;
+            condition.evaluate(player_points.points, 0)
+        }
+        PlayerCondition::Outscores { player_id, other_player_id, gameweek } => {
+            let other_player_points = ctx.accounts.other_player_points
+                .as_ref()
+                .ok_or(error!(ErrorCode::PlayerPointsRequired))?;
+            check_points(player_points, player_id, gameweek)?;
+            check_points(other_player_points, other_player_id, gameweek)?;
+            condition.evaluate(player_points.points, other_player_points.points)
+        }
+    };
+    
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    
+    settle_market(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.market_vault.to_account_info(),
+        &ctx.accounts.treasury_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        market_vault_bump,
+        winning_outcome,
+    )
+}
+
+// Helper function to check the points account matches the market's player and gameweek
+fn check_points(player_points: &PlayerGameweekPoints, player_id: u32, gameweek: u8) -> Result<()> {
+    if player_points.player_id != player_id || player_points.gameweek != gameweek {
+        return Err(error!(ErrorCode::PlayerPointsRequired));
+    }
+    
+    Ok(())
+}
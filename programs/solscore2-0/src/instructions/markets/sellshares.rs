@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::protocol::*;
+use crate::instructions::market::validate_trade;
+use crate::math::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SellShares<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.mechanism == MarketMechanism::Lmsr @ ErrorCode::WrongMarketMechanism,
+    )]
+    pub market: Account<'info, Market>,
+    
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sell_shares(ctx: Context<SellShares>, outcome: u8, amount: u64, min_refund: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let user = &ctx.accounts.user;
+    let market_vault = &ctx.accounts.market_vault;
+    
+    validate_trade(market, outcome, amount)?;
+    
+    let held = position.shares[outcome as usize];
+    if amount > held {
+        return Err(error!(ErrorCode::InsufficientShares));
+    }
+    
+    let refund = lmsr_sell_refund(&market.shares, market.liquidity, outcome as usize, amount)
+        .ok_or(error!(ErrorCode::InsufficientShares))?;
+    
+    if refund < min_refund {
+        return Err(error!(ErrorCode::SlippageExceeded));
+    }
+    
+    // Transfer the refund from the market vault to the user
+    let market_key = market.key();
+    let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+    let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &market_vault.key(),
+            &user.key(),
+            refund,
+        ),
+        &[
+            market_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    // Reduce the cost basis in proportion to the shares sold
+    let cost_sold = ((position.amounts[outcome as usize] as u128 * amount as u128) / held as u128) as u64;
+    
+    market.shares[outcome as usize] -= amount;
+    market.total_pool -= refund;
+    market.cost_basis -= cost_sold;
+    position.shares[outcome as usize] -= amount;
+    position.amounts[outcome as usize] -= cost_sold;
+    
+    Ok(())
+}
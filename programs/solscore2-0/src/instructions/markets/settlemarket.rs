@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::events::*;
+use crate::errors::*;
+
+// Helper function to settle a closed market, shared by every resolution path
+pub(crate) fn settle_market<'info>(
+    market: &mut Account<'info, Market>,
+    treasury: &mut Account<'info, Treasury>,
+    market_vault: &AccountInfo<'info>,
+    treasury_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    market_vault_bump: u8,
+    winning_outcome: u8,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Outcomes are only known once the market has closed
+    if current_time < market.close_time {
+        return Err(error!(ErrorCode::MarketNotClosed));
+    }
+    
+    if winning_outcome as usize >= market.outcomes.len() {
+        return Err(error!(ErrorCode::InvalidOutcome));
+    }
+    
+    market.settle(winning_outcome, treasury.protocol_fee, current_time);
+    
+    // The fee on the losing pools, or what's left of an LMSR subsidy,
+    // goes back to the treasury up front
+    let surplus = if market.mechanism == MarketMechanism::Lmsr { market.lmsr_surplus() } else { 0 };
+    let to_treasury = market.fee_amount + surplus;
+    
+    if to_treasury > 0 {
+        let market_key = market.key();
+        let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+        let signer = &[&seeds[..]];
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &market_vault.key(),
+                &treasury_vault.key(),
+                to_treasury,
+            ),
+            &[
+                market_vault.clone(),
+                treasury_vault.clone(),
+                system_program.clone(),
+            ],
+            signer,
+        )?;
+        
+        treasury.credit_fee(market.fee_amount);
+        treasury.credit_return(surplus);
+        market.total_pool -= surplus;
+    }
+    
+    emit!(MarketResolved {
+        market: market.key(),
+        winning_outcome,
+        total_pool: market.total_pool,
+        fee_amount: market.fee_amount,
+    });
+    
+    Ok(())
+}
+
+// Helper function to pay a resolution bond out of the market's bond vault
+pub(crate) fn release_bond<'info>(
+    market_key: Pubkey,
+    bond_vault: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bond_vault_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    let seeds = &[b"bond-vault".as_ref(), market_key.as_ref(), &[bond_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &bond_vault.key(),
+            &recipient.key(),
+            amount,
+        ),
+        &[
+            bond_vault.clone(),
+            recipient.clone(),
+            system_program.clone(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
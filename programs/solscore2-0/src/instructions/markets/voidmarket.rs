@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use crate::state::market::*;
+use crate::state::treasury::*;
+use crate::state::oracle::*;
+use crate::state::protocol::*;
+use crate::instructions::market::release_bond;
+use crate::events::*;
+use crate::errors::*;
+
+// The admin or resolver can void an unresolved market at any time. Anyone can
+// void it once its fixture is postponed or abandoned, or once `auto_void_delay`
+// has passed since close without a resolution landing. A pending proposal
+// pushes that back to `auto_void_delay` after its dispute deadline, so a
+// disputed market stays with the council. Only the admin can
+// delist, which also slashes the creator's bond to the treasury.
+#[derive(Accounts)]
+pub struct VoidMarket<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(
+        seeds = [b"market-config"],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", &market.id.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Open @ ErrorCode::MarketAlreadyResolved,
+    )]
+    pub market: Account<'info, Market>,
+    
+    // Lets anyone void the market if the fixture was postponed or abandoned
+    #[account(
+        seeds = [b"fixture", &market.fixture_id.to_le_bytes()],
+        bump = fixture.bump,
+    )]
+    pub fixture: Option<Account<'info, Fixture>>,
+    
+    #[account(
+        mut,
+        seeds = [b"resolution", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Pending resolution proposal, if any, its bonds are returned
+    pub resolution: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    /// CHECK: Checked against the resolution proposal
+    pub proposer: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    /// CHECK: Checked against the resolution proposal
+    pub disputer: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"bond-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds resolution bonds
+    pub bond_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"market-vault", market.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the market's collateral
+    pub market_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.markets @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn void_market(ctx: Context<VoidMarket>, delist: bool) -> Result<()> {
+    let market_config = &ctx.accounts.market_config;
+    let caller = ctx.accounts.caller.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    if delist && caller != market_config.admin {
+        return Err(error!(ErrorCode::UnauthorizedAccess));
+    }
+    
+    let resolution_info = ctx.accounts.resolution.to_account_info();
+    let pending_resolution = if resolution_info.data_is_empty() {
+        None
+    } else {
+        Some(Account::<ResolutionProposal>::try_from(&resolution_info)?)
+    };
+    
+    let is_authority = caller == market_config.admin || caller == market_config.resolver;
+    let fixture_cancelled = ctx.accounts.fixture
+        .as_ref()
+        .map_or(false, |fixture| fixture.status.is_cancelled());
+    let void_after = pending_resolution
+        .as_ref()
+        .map_or(ctx.accounts.market.close_time, |resolution| {
+            resolution.dispute_deadline.max(ctx.accounts.market.close_time)
+        })
+        + market_config.auto_void_delay;
+    let auto_void = fixture_cancelled || current_time > void_after;
+    if !is_authority && !auto_void {
+        return Err(error!(ErrorCode::UnauthorizedAccess));
+    }
+    
+    let market_key = ctx.accounts.market.key();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    
+    // A pending proposal can't finalize any more, both sides get their bonds back
+    if let Some(mut resolution) = pending_resolution {
+        let bond_vault = ctx.accounts.bond_vault.to_account_info();
+        let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+        
+        let proposer = ctx.accounts.proposer
+            .as_ref()
+            .filter(|proposer| proposer.key() == resolution.proposer)
+            .ok_or(error!(ErrorCode::UnauthorizedAccess))?;
+        release_bond(
+            market_key,
+            &bond_vault,
+            &proposer.to_account_info(),
+            &system_program,
+            bond_vault_bump,
+            resolution.proposer_bond,
+        )?;
+        
+        if let Some(disputer_key) = resolution.disputer {
+            let disputer = ctx.accounts.disputer
+                .as_ref()
+                .filter(|disputer| disputer.key() == disputer_key)
+                .ok_or(error!(ErrorCode::UnauthorizedAccess))?;
+            release_bond(
+                market_key,
+                &bond_vault,
+                &disputer.to_account_info(),
+                &system_program,
+                bond_vault_bump,
+                resolution.disputer_bond,
+            )?;
+        }
+        
+        resolution.status = ResolutionStatus::Finalized;
+        resolution.exit(ctx.program_id)?;
+    }
+    
+    let market = &mut ctx.accounts.market;
+    market.status = MarketStatus::Voided;
+    market.resolved_at = current_time;
+    
+    // Delisted creators lose their bond
+    if delist {
+        let creation_bond = market.creation_bond;
+        market.delisted = true;
+        market.creation_bond = 0;
+        
+        let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+        release_bond(
+            market_key,
+            &ctx.accounts.bond_vault.to_account_info(),
+            &ctx.accounts.treasury_vault.to_account_info(),
+            &system_program,
+            bond_vault_bump,
+            creation_bond,
+        )?;
+        ctx.accounts.treasury.credit_fee(creation_bond);
+    }
+    
+    // Whatever is left of an LMSR subsidy after refunds goes back to the treasury
+    let surplus = market.void_surplus();
+    if surplus > 0 {
+        let market_vault_bump = *ctx.bumps.get("market_vault").unwrap();
+        let seeds = &[b"market-vault".as_ref(), market_key.as_ref(), &[market_vault_bump]];
+        let signer = &[&seeds[..]];
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.market_vault.key(),
+                &ctx.accounts.treasury_vault.key(),
+                surplus,
+            ),
+            &[
+                ctx.accounts.market_vault.to_account_info(),
+                ctx.accounts.treasury_vault.to_account_info(),
+                system_program.clone(),
+            ],
+            signer,
+        )?;
+        
+        ctx.accounts.treasury.credit_return(surplus);
+        market.total_pool -= surplus;
+    }
+    
+    // LMSR refunds share whatever is left in the pool, capped at cost
+    if market.mechanism == MarketMechanism::Lmsr {
+        market.distributable = market.total_pool.min(market.cost_basis);
+    }
+    
+    emit!(MarketVoided {
+        market: market_key,
+        auto_void: !is_authority,
+        delisted: delist,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::state::oracle::*;
+use crate::ed25519::verify_signed_payload;
+use crate::errors::*;
+
+// Anyone can relay a fixture payload as long as the oracle publisher signed it
+#[derive(Accounts)]
+#[instruction(payload: FixturePayload)]
+pub struct CreateFixture<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"oracle-config"],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + 8 + 4 + 1 + 1 + 1 + 8 + 1 + 2 + 2
+            + (4 + MAX_FIXTURE_HISTORY * FixtureStatusChange::SIZE) + 1, // Adjust space calculation
+        seeds = [b"fixture", &payload.fixture_id.to_le_bytes()],
+        bump
+    )]
+    pub fixture: Account<'info, Fixture>,
+    
+    #[account(address = sysvar::instructions::ID)]
+    /// CHECK: Instructions sysvar, used to find the ed25519 verify instruction
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_fixture(ctx: Context<CreateFixture>, payload: FixturePayload) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+    let bump = *ctx.bumps.get("fixture").unwrap();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    verify_signed_payload(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &ctx.accounts.oracle_config.publisher,
+        &payload.try_to_vec()?,
+    )?;
+    
+    validate_fixture_payload(&payload)?;
+    
+    // The history starts with the fixture's initial status
+    fixture.fixture_id = payload.fixture_id;
+    fixture.status = payload.status;
+    fixture.kickoff = payload.kickoff;
+    fixture.history = vec![FixtureStatusChange {
+        status: payload.status,
+        kickoff: payload.kickoff,
+        changed_at: current_time,
+    }];
+    fixture.bump = bump;
+    fixture.apply(&payload, current_time);
+    
+    Ok(())
+}
+
+// Helper function to validate a fixture payload
+pub(crate) fn validate_fixture_payload(payload: &FixturePayload) -> Result<()> {
+    if payload.home_team == payload.away_team || payload.gameweek == 0 {
+        return Err(error!(ErrorCode::InvalidFixture));
+    }
+    
+    // Finished fixtures need a final score
+    let has_score = payload.home_score.is_some() && payload.away_score.is_some();
+    if (payload.status == FixtureStatus::Finished) != has_score {
+        return Err(error!(ErrorCode::InvalidFixture));
+    }
+    
+    Ok(())
+}
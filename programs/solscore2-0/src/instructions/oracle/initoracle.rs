@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::oracle::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeOracle<'info> {
+    #[account(
+        mut,
+        constraint = global_state.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 32 + 1, // Adjust space calculation
+        seeds = [b"oracle-config"],
+        bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_oracle(ctx: Context<InitializeOracle>, publisher: Pubkey) -> Result<()> {
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    let bump = *ctx.bumps.get("oracle_config").unwrap();
+    
+    oracle_config.admin = ctx.accounts.admin.key();
+    oracle_config.publisher = publisher;
+    oracle_config.bump = bump;
+    
+    Ok(())
+}
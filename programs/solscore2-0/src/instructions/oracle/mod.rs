@@ -0,0 +1,9 @@
+pub mod initoracle;
+pub mod publishplayerpoints;
+pub mod createfixture;
+pub mod updatefixture;
+
+pub use initoracle::*;
+pub use publishplayerpoints::*;
+pub use createfixture::*;
+pub use updatefixture::*;
\ No newline at end of file
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::state::oracle::*;
+use crate::state::fpl::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(player_id: u32, gameweek: u8)]
+pub struct PublishPlayerPoints<'info> {
+    #[account(
+        mut,
+        constraint = oracle_config.publisher == publisher.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub publisher: Signer<'info>,
+    
+    #[account(
+        seeds = [b"oracle-config"],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        init,
+        payer = publisher,
+        space = 8 + 4 + 1 + 2 + 8 + 1, // Adjust space calculation
+        seeds = [b"player-points", &player_id.to_le_bytes(), &[gameweek]],
+        bump
+    )]
+    pub player_points: Account<'info, PlayerGameweekPoints>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn publish_player_points(
+    ctx: Context<PublishPlayerPoints>,
+    player_id: u32,
+    gameweek: u8,
+    points: i16,
+) -> Result<()> {
+    let player_points = &mut ctx.accounts.player_points;
+    let bump = *ctx.bumps.get("player_points").unwrap();
+    
+    // Points can't be published for a gameweek that hasn't started
+    if gameweek == 0 || gameweek > ctx.accounts.global_state.current_gameweek {
+        return Err(error!(ErrorCode::InvalidGameweek));
+    }
+    
+    player_points.player_id = player_id;
+    player_points.gameweek = gameweek;
+    player_points.points = points;
+    player_points.published_at = Clock::get()?.unix_timestamp;
+    player_points.bump = bump;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CorrectPlayerPoints<'info> {
+    #[account(
+        constraint = oracle_config.publisher == publisher.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub publisher: Signer<'info>,
+    
+    #[account(
+        seeds = [b"oracle-config"],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"player-points", &player_points.player_id.to_le_bytes(), &[player_points.gameweek]],
+        bump = player_points.bump,
+    )]
+    pub player_points: Account<'info, PlayerGameweekPoints>,
+}
+
+// Bonus points and scoring changes land until the gameweek is finalized
+pub fn correct_player_points(ctx: Context<CorrectPlayerPoints>, points: i16) -> Result<()> {
+    let player_points = &mut ctx.accounts.player_points;
+    
+    if player_points.gameweek <= ctx.accounts.global_state.finalized_gameweek {
+        return Err(error!(ErrorCode::GameweekFinalized));
+    }
+    
+    player_points.points = points;
+    player_points.published_at = Clock::get()?.unix_timestamp;
+    
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::state::oracle::*;
+use crate::ed25519::verify_signed_payload;
+use crate::instructions::oracle::validate_fixture_payload;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct UpdateFixture<'info> {
+    pub relayer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"oracle-config"],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"fixture", &fixture.fixture_id.to_le_bytes()],
+        bump = fixture.bump,
+    )]
+    pub fixture: Account<'info, Fixture>,
+    
+    #[account(address = sysvar::instructions::ID)]
+    /// CHECK: Instructions sysvar, used to find the ed25519 verify instruction
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn update_fixture(ctx: Context<UpdateFixture>, payload: FixturePayload) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+    
+    verify_signed_payload(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &ctx.accounts.oracle_config.publisher,
+        &payload.try_to_vec()?,
+    )?;
+    
+    validate_fixture_payload(&payload)?;
+    
+    if payload.fixture_id != fixture.fixture_id {
+        return Err(error!(ErrorCode::InvalidFixture));
+    }
+    
+    // Older payloads can't be replayed over newer ones
+    if payload.sequence <= fixture.sequence {
+        return Err(error!(ErrorCode::StaleFixtureUpdate));
+    }
+    
+    fixture.apply(&payload, Clock::get()?.unix_timestamp);
+    
+    Ok(())
+}
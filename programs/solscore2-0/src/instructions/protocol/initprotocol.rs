@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::protocol::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolState<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 32 + PauseFlags::SIZE + 8 + 1, // Adjust space calculation
+        seeds = [b"protocol-state"],
+        bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_protocol_state(ctx: Context<InitializeProtocolState>, guardian: Pubkey) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let bump = *ctx.bumps.get("protocol_state").unwrap();
+    
+    protocol_state.admin = ctx.accounts.admin.key();
+    protocol_state.guardian = guardian;
+    protocol_state.paused = PauseFlags::default();
+    protocol_state.last_paused_at = 0;
+    protocol_state.bump = bump;
+    
+    Ok(())
+}
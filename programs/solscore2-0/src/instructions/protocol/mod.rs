@@ -0,0 +1,5 @@
+pub mod initprotocol;
+pub mod pause;
+
+pub use initprotocol::*;
+pub use pause::*;
\ No newline at end of file
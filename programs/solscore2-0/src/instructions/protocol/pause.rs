@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::protocol::*;
+use crate::events::*;
+use crate::errors::*;
+
+// The guardian (or admin) can pause without delay, unpausing is queued through the timelock
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        constraint = authority.key() == protocol_state.guardian
+            || authority.key() == protocol_state.admin @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn pause(ctx: Context<Pause>, flags: PauseFlags) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    
+    if flags.is_empty() {
+        return Err(error!(ErrorCode::InvalidPauseFlags));
+    }
+    
+    protocol_state.paused.pause(&flags);
+    protocol_state.last_paused_at = Clock::get()?.unix_timestamp;
+    
+    emit!(PauseUpdated {
+        authority: ctx.accounts.authority.key(),
+        paused: protocol_state.paused,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+use crate::state::stake::*;
+use crate::state::fpl::*;
+use crate::state::treasury::*;
+use crate::events::*;
+use crate::state::protocol::*;
+use crate::instructions::reward::credit_referral;
+use crate::errors::*;
+
+// Stake accounts are passed as remaining accounts, in the same order as `stake_ids`
+#[derive(Accounts)]
+pub struct ClaimAllRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [b"fpl-user", user.key().as_ref()],
+        bump = fpl_user.bump,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        seeds = [b"reward-config"],
+        bump = reward_config.bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    // Required when vesting is enabled in the reward config
+    #[account(
+        mut,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump = vesting_schedule.bump,
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    // Required when the user was referred
+    #[account(
+        mut,
+        seeds = [b"referral", referral_stats.referrer.as_ref()],
+        bump = referral_stats.bump,
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_all<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimAllRewards<'info>>,
+    stake_ids: Vec<u64>,
+) -> Result<()> {
+    let fpl_user = &ctx.accounts.fpl_user;
+    let reward_config = &ctx.accounts.reward_config;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let reward_vault = &ctx.accounts.reward_vault;
+    let user = &ctx.accounts.user;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let stake_accounts = ctx.remaining_accounts;
+    
+    // Every stake account needs a matching stake id
+    if stake_accounts.is_empty() || stake_accounts.len() != stake_ids.len() {
+        return Err(error!(ErrorCode::InvalidStakeAccounts));
+    }
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let streak = fpl_user.effective_streak(ctx.accounts.global_state.finalized_gameweek);
+    let mut total_reward: u64 = 0;
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(stake_accounts.len());
+    
+    for (stake_info, stake_id) in stake_accounts.iter().zip(stake_ids.iter()) {
+        // Reject duplicates so a stake can't be paid twice
+        if seen.contains(stake_info.key) {
+            return Err(error!(ErrorCode::InvalidStakeAccounts));
+        }
+        seen.push(stake_info.key());
+        
+        // Deserializing checks the account is owned by this program
+        let mut stake = Account::<Stake>::try_from(stake_info)?;
+        
+        if stake.owner != user.key() {
+            return Err(error!(ErrorCode::UnauthorizedAccess));
+        }
+        
+        if !stake.is_active {
+            return Err(error!(ErrorCode::StakeNotActive));
+        }
+        
+        // Check the stake is the PDA derived for this user and id
+        let expected_stake = Pubkey::create_program_address(
+            &[b"stake", user.key().as_ref(), &stake_id.to_le_bytes(), &[stake.bump]],
+            ctx.program_id,
+        ).map_err(|_| error!(ErrorCode::InvalidStakeAccounts))?;
+        
+        if expected_stake != stake_info.key() {
+            return Err(error!(ErrorCode::InvalidStakeAccounts));
+        }
+        
+        // Stakes that aren't due yet are skipped rather than failing the whole claim
+        let time_since_last_claim = current_time - stake.last_claim_time;
+        if time_since_last_claim < reward_config.distribution_frequency as i64 {
+            continue;
+        }
+        
+        let reward = calculate_stake_reward(
+            stake.amount,
+            time_since_last_claim as u64,
+            fpl_user,
+            reward_config,
+            streak,
+        );
+        
+        total_reward += reward;
+        
+        // Update stake last claim time
+        stake.last_claim_time = current_time;
+        stake.exit(ctx.program_id)?;
+    }
+    
+    if total_reward == 0 {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    // Referrer share is paid by the pool on top of the user's reward
+    let referral_reward = credit_referral(
+        fpl_user,
+        ctx.accounts.referral_stats.as_mut(),
+        reward_config,
+        total_reward,
+    )?;
+    
+    // Check if reward pool has enough funds
+    if total_reward + referral_reward > reward_pool.total_rewards - reward_pool.distributed_rewards {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    // Update reward pool
+    reward_pool.distributed_rewards += total_reward + referral_reward;
+    
+    // Protocol fee comes out of the gross reward and goes to the treasury
+    let (protocol_fee, net_reward) = treasury.split_protocol_fee(total_reward);
+    treasury.credit_fee(protocol_fee);
+    
+    let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+    let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &reward_vault.key(),
+                &treasury_vault.key(),
+                protocol_fee,
+            ),
+            &[
+                reward_vault.to_account_info(),
+                treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
+    emit!(RewardClaimed {
+        user: user.key(),
+        gross_amount: total_reward,
+        fee_amount: protocol_fee,
+        net_amount: net_reward,
+        vested: reward_config.vesting_enabled,
+    });
+    
+    // In vesting mode the net reward stays in the vault and unlocks over time
+    if reward_config.vesting_enabled {
+        let vesting_schedule = ctx.accounts.vesting_schedule
+            .as_mut()
+            .ok_or(error!(ErrorCode::VestingScheduleRequired))?;
+        vesting_schedule.extend(net_reward, current_time, reward_config.vesting_duration());
+        
+        return Ok(());
+    }
+    
+    // Transfer the combined rewards to user
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &reward_vault.key(),
+            &user.key(),
+            net_reward,
+        ),
+        &[
+            reward_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral_stats.bump,
+        constraint = referral_stats.referrer == referrer.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    let referral_stats = &mut ctx.accounts.referral_stats;
+    let reward_vault = &ctx.accounts.reward_vault;
+    let referrer = &ctx.accounts.referrer;
+    
+    // Already reserved in the reward pool when credited
+    let amount = referral_stats.total_earned - referral_stats.total_claimed;
+    if amount == 0 {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    referral_stats.total_claimed += amount;
+    
+    // Transfer referral rewards to the referrer
+    let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+    let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &reward_vault.key(),
+            &referrer.key(),
+            amount,
+        ),
+        &[
+            reward_vault.to_account_info(),
+            referrer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
@@ -2,9 +2,13 @@ use anchor_lang::prelude::*;
 use crate::state::reward::*;
 use crate::state::stake::*;
 use crate::state::fpl::*;
+use crate::state::treasury::*;
+use crate::events::*;
+use crate::state::protocol::*;
 use crate::errors::*;
 
 #[derive(Accounts)]
+#[instruction(stake_id: u64)]
 pub struct ClaimRewards<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -24,6 +28,12 @@ pub struct ClaimRewards<'info> {
     )]
     pub fpl_user: Account<'info, FplUser>,
     
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
     #[account(
         seeds = [b"reward-config"],
         bump = reward_config.bump,
@@ -45,16 +55,56 @@ pub struct ClaimRewards<'info> {
     /// CHECK: This is the PDA that holds rewards
     pub reward_vault: UncheckedAccount<'info>,
     
+    // Required when vesting is enabled in the reward config
+    #[account(
+        mut,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump = vesting_schedule.bump,
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    // Required when the user was referred
+    #[account(
+        mut,
+        seeds = [b"referral", referral_stats.referrer.as_ref()],
+        bump = referral_stats.bump,
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+pub fn claim_rewards(ctx: Context<ClaimRewards>, _stake_id: u64) -> Result<()> {
     let stake = &mut ctx.accounts.stake;
     let fpl_user = &ctx.accounts.fpl_user;
     let reward_config = &ctx.accounts.reward_config;
     let reward_pool = &mut ctx.accounts.reward_pool;
     let reward_vault = &ctx.accounts.reward_vault;
     let user = &ctx.accounts.user;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
     
     let current_time = Clock::get()?.unix_timestamp;
     let time_since_last_claim = current_time - stake.last_claim_time;
@@ -65,37 +115,81 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     }
     
     // Calculate rewards based on stake amount, time, and FPL performance
-    let base_reward = calculate_base_reward(
+    let total_reward = calculate_stake_reward(
         stake.amount,
         time_since_last_claim as u64,
-        reward_config.base_apy,
+        fpl_user,
+        reward_config,
+        fpl_user.effective_streak(ctx.accounts.global_state.finalized_gameweek),
     );
     
-    // Performance multiplier based on FPL score
-    let performance_factor = 100 + (fpl_user.weekly_score * reward_config.score_multiplier as u32) / 100;
-    let total_reward = (base_reward * performance_factor as u64) / 100;
+    // Referrer share is paid by the pool on top of the user's reward
+    let referral_reward = credit_referral(
+        fpl_user,
+        ctx.accounts.referral_stats.as_mut(),
+        reward_config,
+        total_reward,
+    )?;
     
     // Check if reward pool has enough funds
-    if total_reward > reward_pool.total_rewards - reward_pool.distributed_rewards {
+    if total_reward + referral_reward > reward_pool.total_rewards - reward_pool.distributed_rewards {
         return Err(error!(ErrorCode::NoRewardsAvailable));
     }
     
     // Update reward pool
-    reward_pool.distributed_rewards += total_reward;
+    reward_pool.distributed_rewards += total_reward + referral_reward;
     
     // Update stake last claim time
     stake.last_claim_time = current_time;
     
-    // Transfer rewards to user
+    // Protocol fee comes out of the gross reward and goes to the treasury
+    let (protocol_fee, net_reward) = treasury.split_protocol_fee(total_reward);
+    treasury.credit_fee(protocol_fee);
+    
     let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
     let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
     let signer = &[&seeds[..]];
     
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &reward_vault.key(),
+                &treasury_vault.key(),
+                protocol_fee,
+            ),
+            &[
+                reward_vault.to_account_info(),
+                treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
+    emit!(RewardClaimed {
+        user: user.key(),
+        gross_amount: total_reward,
+        fee_amount: protocol_fee,
+        net_amount: net_reward,
+        vested: reward_config.vesting_enabled,
+    });
+    
+    // In vesting mode the net reward stays in the vault and unlocks over time
+    if reward_config.vesting_enabled {
+        let vesting_schedule = ctx.accounts.vesting_schedule
+            .as_mut()
+            .ok_or(error!(ErrorCode::VestingScheduleRequired))?;
+        vesting_schedule.extend(net_reward, current_time, reward_config.vesting_duration());
+        
+        return Ok(());
+    }
+    
+    // Transfer rewards to user
     anchor_lang::solana_program::program::invoke_signed(
         &anchor_lang::solana_program::system_instruction::transfer(
             &reward_vault.key(),
             &user.key(),
-            total_reward,
+            net_reward,
         ),
         &[
             reward_vault.to_account_info(),
@@ -108,6 +202,49 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     Ok(())
 }
 
+// Helper function to calculate a stake's reward including the FPL performance factor
+pub(crate) fn calculate_stake_reward(
+    stake_amount: u64,
+    time_period: u64,
+    fpl_user: &FplUser,
+    reward_config: &RewardConfig,
+    streak: u16,
+) -> u64 {
+    let base_reward = calculate_base_reward(
+        stake_amount,
+        time_period,
+        reward_config.base_apy,
+    );
+    
+    // Performance multiplier based on FPL score, boosted by the participation streak
+    let performance_factor = 100 + (fpl_user.weekly_score * reward_config.score_multiplier as u32) / 100;
+    let streak_factor = 100 + reward_config.streak_bonus_for(streak);
+    (base_reward * performance_factor as u64 * streak_factor) / 10_000
+}
+
+// Helper function to credit the referrer's share of a claim, returns the amount credited
+pub(crate) fn credit_referral<'info>(
+    fpl_user: &FplUser,
+    referral_stats: Option<&mut Account<'info, ReferralStats>>,
+    reward_config: &RewardConfig,
+    reward: u64,
+) -> Result<u64> {
+    let referrer = match fpl_user.referrer {
+        Some(referrer) => referrer,
+        None => return Ok(0),
+    };
+    
+    let referral_stats = referral_stats.ok_or(error!(ErrorCode::ReferralStatsRequired))?;
+    if referral_stats.referrer != referrer {
+        return Err(error!(ErrorCode::InvalidReferrer));
+    }
+    
+    let referral_reward = (reward * reward_config.referral_share as u64) / 100;
+    referral_stats.total_earned += referral_reward;
+    
+    Ok(referral_reward)
+}
+
 // Helper function to calculate base rewards
 fn calculate_base_reward(stake_amount: u64, time_period: u64, apy: u8) -> u64 {
     // Convert APY to per-second rate
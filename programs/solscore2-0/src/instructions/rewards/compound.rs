@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+use crate::state::stake::*;
+use crate::state::fpl::*;
+use crate::state::treasury::*;
+use crate::events::*;
+use crate::state::protocol::*;
+use crate::instructions::reward::credit_referral;
+use crate::errors::*;
+
+// Permissionless crank, anyone can compound a stake that has opted in
+#[derive(Accounts)]
+#[instruction(stake_id: u64)]
+pub struct CompoundRewards<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake", stake.owner.as_ref(), &stake_id.to_le_bytes()],
+        bump = stake.bump,
+        constraint = stake.is_active @ ErrorCode::StakeNotActive,
+        constraint = stake.auto_compound @ ErrorCode::AutoCompoundDisabled,
+    )]
+    pub stake: Account<'info, Stake>,
+    
+    #[account(
+        seeds = [b"fpl-user", stake.owner.as_ref()],
+        bump = fpl_user.bump,
+        constraint = fpl_user.key() == stake.fpl_user @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
+    #[account(
+        seeds = [b"reward-config"],
+        bump = reward_config.bump,
+        // Compounded rewards would skip the vesting schedule and leave with the principal
+        constraint = !reward_config.vesting_enabled @ ErrorCode::CompoundingWhileVesting,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    // Required when the user was referred
+    #[account(
+        mut,
+        seeds = [b"referral", referral_stats.referrer.as_ref()],
+        bump = referral_stats.bump,
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds the staked SOL
+    pub stake_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn compound(ctx: Context<CompoundRewards>, _stake_id: u64) -> Result<()> {
+    let stake = &mut ctx.accounts.stake;
+    let fpl_user = &ctx.accounts.fpl_user;
+    let reward_config = &ctx.accounts.reward_config;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let reward_vault = &ctx.accounts.reward_vault;
+    let stake_vault = &ctx.accounts.stake_vault;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let cranker = &ctx.accounts.cranker;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let time_since_last_claim = current_time - stake.last_claim_time;
+    
+    // Same cadence as a manual claim
+    if time_since_last_claim < reward_config.distribution_frequency as i64 {
+        return Err(error!(ErrorCode::TooEarlyToClaim));
+    }
+    
+    let total_reward = calculate_stake_reward(
+        stake.amount,
+        time_since_last_claim as u64,
+        fpl_user,
+        reward_config,
+        fpl_user.effective_streak(ctx.accounts.global_state.finalized_gameweek),
+    );
+    
+    if total_reward == 0 {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    // Compounded rewards earn the referrer the same share as a manual claim
+    let referral_reward = credit_referral(
+        fpl_user,
+        ctx.accounts.referral_stats.as_mut(),
+        reward_config,
+        total_reward,
+    )?;
+    
+    // Check if reward pool has enough funds
+    if total_reward + referral_reward > reward_pool.total_rewards - reward_pool.distributed_rewards {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    // Protocol fee comes out of the gross reward like a manual claim,
+    // the cranker tip is paid out of what's left
+    let (protocol_fee, net_reward) = treasury.split_protocol_fee(total_reward);
+    treasury.credit_fee(protocol_fee);
+    
+    let tip = (net_reward * reward_config.compound_tip_bps as u64) / 10_000;
+    let compounded = net_reward - tip;
+    
+    // Update reward pool
+    reward_pool.distributed_rewards += total_reward + referral_reward;
+    
+    // Grow the principal
+    stake.amount += compounded;
+    stake.last_claim_time = current_time;
+    
+    let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+    let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &reward_vault.key(),
+                &treasury_vault.key(),
+                protocol_fee,
+            ),
+            &[
+                reward_vault.to_account_info(),
+                treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
+    // Move compounded rewards into the stake vault
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &reward_vault.key(),
+            &stake_vault.key(),
+            compounded,
+        ),
+        &[
+            reward_vault.to_account_info(),
+            stake_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    // Pay the cranker
+    if tip > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &reward_vault.key(),
+                &cranker.key(),
+                tip,
+            ),
+            &[
+                reward_vault.to_account_info(),
+                cranker.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
+    emit!(RewardClaimed {
+        user: stake.owner,
+        gross_amount: total_reward,
+        fee_amount: protocol_fee,
+        net_amount: net_reward,
+        vested: false,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+// Early exit: pays out what has vested and forfeits the rest to the reward pool
+#[derive(Accounts)]
+pub struct ExitVesting<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump = vesting_schedule.bump,
+        constraint = vesting_schedule.owner == user.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn exit_vesting(ctx: Context<ExitVesting>) -> Result<()> {
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let reward_vault = &ctx.accounts.reward_vault;
+    let user = &ctx.accounts.user;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let amount = vesting_schedule.withdrawable_amount(current_time);
+    let forfeited = vesting_schedule.total_amount - vesting_schedule.vested_amount(current_time);
+    
+    // Unvested rewards go back to the pool
+    reward_pool.distributed_rewards -= forfeited;
+    
+    if amount > 0 {
+        // Transfer vested rewards to user
+        let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+        let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
+        let signer = &[&seeds[..]];
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &reward_vault.key(),
+                &user.key(),
+                amount,
+            ),
+            &[
+                reward_vault.to_account_info(),
+                user.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
+    Ok(())
+}
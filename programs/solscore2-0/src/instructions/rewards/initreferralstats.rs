@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+
+#[derive(Accounts)]
+pub struct InitializeReferralStats<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + 32 + 4 + 8 + 8 + 1, // Adjust space calculation
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_referral_stats(ctx: Context<InitializeReferralStats>) -> Result<()> {
+    let referral_stats = &mut ctx.accounts.referral_stats;
+    let bump = *ctx.bumps.get("referral_stats").unwrap();
+    
+    referral_stats.referrer = ctx.accounts.referrer.key();
+    referral_stats.referee_count = 0;
+    referral_stats.total_earned = 0;
+    referral_stats.total_claimed = 0;
+    referral_stats.bump = bump;
+    
+    Ok(())
+}
@@ -6,6 +6,12 @@ pub struct RewardConfigParams {
     pub base_apy: u8,
     pub score_multiplier: u8,
     pub distribution_frequency: u64,
+    pub compound_tip_bps: u16,
+    pub vesting_enabled: bool,
+    pub vesting_days: u16,
+    pub streak_bonus: u8,
+    pub max_streak_bonus: u8,
+    pub referral_share: u8,
 }
 
 #[derive(Accounts)]
@@ -16,7 +22,7 @@ pub struct InitializeRewardConfig<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 1 + 1 + 8 + 1, // Adjust space calculation
+        space = 8 + 32 + 1 + 1 + 8 + 2 + 1 + 2 + 1 + 1 + 1 + 1, // Adjust space calculation
         seeds = [b"reward-config"],
         bump
     )]
@@ -30,7 +36,17 @@ pub fn initialize_reward_config(ctx: Context<InitializeRewardConfig>, params: Re
     let bump = *ctx.bumps.get("reward_config").unwrap();
     
     // Validate parameters
-    if params.base_apy > 100 {
+    if params.base_apy > 100 || params.max_streak_bonus > 100 || params.referral_share > 100 {
+        return Err(error!(ErrorCode::InvalidRewardParameter));
+    }
+    
+    // Cranker tip is capped at 10% of the compounded amount
+    if params.compound_tip_bps > MAX_COMPOUND_TIP_BPS {
+        return Err(error!(ErrorCode::InvalidRewardParameter));
+    }
+    
+    // Vesting needs a non-zero duration
+    if params.vesting_enabled && params.vesting_days == 0 {
         return Err(error!(ErrorCode::InvalidRewardParameter));
     }
     
@@ -38,6 +54,12 @@ pub fn initialize_reward_config(ctx: Context<InitializeRewardConfig>, params: Re
     reward_config.base_apy = params.base_apy;
     reward_config.score_multiplier = params.score_multiplier;
     reward_config.distribution_frequency = params.distribution_frequency;
+    reward_config.compound_tip_bps = params.compound_tip_bps;
+    reward_config.vesting_enabled = params.vesting_enabled;
+    reward_config.vesting_days = params.vesting_days;
+    reward_config.streak_bonus = params.streak_bonus;
+    reward_config.max_streak_bonus = params.max_streak_bonus;
+    reward_config.referral_share = params.referral_share;
     reward_config.bump = bump;
     
     Ok(())
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+
+#[derive(Accounts)]
+pub struct InitializeVestingSchedule<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1, // Adjust space calculation
+        seeds = [b"vesting", user.key().as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_vesting_schedule(ctx: Context<InitializeVestingSchedule>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let bump = *ctx.bumps.get("vesting_schedule").unwrap();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    vesting_schedule.owner = ctx.accounts.user.key();
+    vesting_schedule.total_amount = 0;
+    vesting_schedule.withdrawn_amount = 0;
+    vesting_schedule.released_amount = 0;
+    vesting_schedule.start_time = current_time;
+    vesting_schedule.end_time = current_time;
+    vesting_schedule.bump = bump;
+    
+    Ok(())
+}
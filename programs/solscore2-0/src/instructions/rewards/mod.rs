@@ -2,8 +2,24 @@ pub mod initrewardsconfig;
 pub mod initrewardpool;
 pub mod claimrewards;
 pub mod distrewards;
+pub mod claimall;
+pub mod setautocompound;
+pub mod compound;
+pub mod initvesting;
+pub mod withdrawvested;
+pub mod exitvesting;
+pub mod initreferralstats;
+pub mod claimreferral;
 
 pub use initrewardsconfig::*;
 pub use initrewardpool::*;
 pub use claimrewards::*;
-pub use distrewards::*;
\ No newline at end of file
+pub use distrewards::*;
+pub use claimall::*;
+pub use setautocompound::*;
+pub use compound::*;
+pub use initvesting::*;
+pub use withdrawvested::*;
+pub use exitvesting::*;
+pub use initreferralstats::*;
+pub use claimreferral::*;
\ No newline at end of file
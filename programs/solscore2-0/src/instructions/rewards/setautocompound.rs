@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::stake::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(stake_id: u64)]
+pub struct SetAutoCompound<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake", user.key().as_ref(), &stake_id.to_le_bytes()],
+        bump = stake.bump,
+        constraint = stake.owner == user.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = stake.is_active @ ErrorCode::StakeNotActive,
+    )]
+    pub stake: Account<'info, Stake>,
+}
+
+pub fn set_auto_compound(ctx: Context<SetAutoCompound>, _stake_id: u64, enabled: bool) -> Result<()> {
+    let stake = &mut ctx.accounts.stake;
+    
+    stake.auto_compound = enabled;
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::reward::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump = vesting_schedule.bump,
+        constraint = vesting_schedule.owner == user.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.claims @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let reward_vault = &ctx.accounts.reward_vault;
+    let user = &ctx.accounts.user;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let amount = vesting_schedule.withdrawable_amount(current_time);
+    
+    if amount == 0 {
+        return Err(error!(ErrorCode::NothingVested));
+    }
+    
+    // Everything vested so far has now been paid out
+    vesting_schedule.released_amount = 0;
+    vesting_schedule.withdrawn_amount = vesting_schedule.vested_amount(current_time);
+    
+    // Transfer vested rewards to user
+    let reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+    let seeds = &[b"reward-vault".as_ref(), &[reward_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &reward_vault.key(),
+            &user.key(),
+            amount,
+        ),
+        &[
+            reward_vault.to_account_info(),
+            user.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
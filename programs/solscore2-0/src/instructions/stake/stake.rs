@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token};
 use crate::state::stake::*;
 use crate::state::fpl::*;
+use crate::state::protocol::*;
 use crate::errors::*;
 
 #[derive(Accounts)]
@@ -16,10 +17,16 @@ pub struct CreateStake<'info> {
     )]
     pub fpl_user: Account<'info, FplUser>,
     
+    #[account(
+        seeds = [b"fpl-global"],
+        bump = global_state.bump,
+    )]
+    pub global_state: Account<'info, FplGlobalState>,
+    
     #[account(
         init,
         payer = user,
-        space = 8 + 32 + 8 + 8 + 8 + 32 + 1 + 8 + 1, // Adjust space calculation
+        space = 8 + 32 + 8 + 8 + 8 + 32 + 1 + 8 + 1 + 1, // Adjust space calculation
         seeds = [b"stake", user.key().as_ref(), &stake_count.to_le_bytes()],
         bump
     )]
@@ -46,6 +53,13 @@ pub struct CreateStake<'info> {
     )]
     pub stake_count: Account<'info, StakeCount>,
     
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.staking @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -90,10 +104,20 @@ pub fn create_stake(ctx: Context<CreateStake>, amount: u64, lock_period: u64) ->
     stake.fpl_user = ctx.accounts.fpl_user.key();
     stake.is_active = true;
     stake.last_claim_time = Clock::get()?.unix_timestamp;
+    stake.auto_compound = false;
     stake.bump = bump;
     
     // Increment stake count
     stake_count.count += 1;
     
+    // A new staking run counts from the current gameweek, or the next one once its deadline has passed
+    let fpl_user = &mut ctx.accounts.fpl_user;
+    if fpl_user.active_stakes == 0 {
+        let global_state = &ctx.accounts.global_state;
+        let past_deadline = Clock::get()?.unix_timestamp > global_state.gameweek_deadline;
+        fpl_user.staked_since_gameweek = (global_state.current_gameweek + past_deadline as u8).max(1);
+    }
+    fpl_user.active_stakes += 1;
+    
     Ok(())
 }
\ No newline at end of file
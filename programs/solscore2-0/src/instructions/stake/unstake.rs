@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::stake::*;
 use crate::state::treasury::*;
+use crate::state::fpl::*;
+use crate::state::protocol::*;
 use crate::errors::*;
 
 #[derive(Accounts)]
@@ -17,6 +19,14 @@ pub struct Unstake<'info> {
     )]
     pub stake: Account<'info, Stake>,
     
+    #[account(
+        mut,
+        seeds = [b"fpl-user", user.key().as_ref()],
+        bump = fpl_user.bump,
+        constraint = fpl_user.key() == stake.fpl_user @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub fpl_user: Account<'info, FplUser>,
+    
     #[account(
         seeds = [b"stake-config"],
         bump = stake_config.bump,
@@ -38,6 +48,21 @@ pub struct Unstake<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
     
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.unstaking @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -53,24 +78,51 @@ pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
     
     // Calculate amount to return after potential penalties
     let mut return_amount = stake.amount;
+    let mut fee_amount = 0;
     
     // Apply early withdrawal fee if applicable
     if stake_duration < stake.lock_period as i64 {
-        let fee_amount = (stake.amount * stake_config.early_withdrawal_fee as u64) / 100;
+        fee_amount = (stake.amount * stake_config.early_withdrawal_fee as u64) / 100;
         return_amount -= fee_amount;
         
         // Add fee to treasury
-        treasury.total_fees += fee_amount;
+        treasury.credit_fee(fee_amount);
     }
     
     // Mark stake as inactive
     stake.is_active = false;
     
+    // Fully unstaking breaks the streak
+    let fpl_user = &mut ctx.accounts.fpl_user;
+    fpl_user.active_stakes -= 1;
+    if fpl_user.active_stakes == 0 {
+        fpl_user.streak = 0;
+    }
+    
     // Transfer SOL back to the user
     let stake_vault_bump = *ctx.bumps.get("stake_vault").unwrap();
     let seeds = &[b"stake-vault".as_ref(), &[stake_vault_bump]];
     let signer = &[&seeds[..]];
     
+    // Move the fee into the treasury vault so the ledger matches the balance
+    if fee_amount > 0 {
+        let treasury_vault = &ctx.accounts.treasury_vault;
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                &stake_vault.key(),
+                &treasury_vault.key(),
+                fee_amount,
+            ),
+            &[
+                stake_vault.to_account_info(),
+                treasury_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer,
+        )?;
+    }
+    
     anchor_lang::solana_program::program::invoke_signed(
         &anchor_lang::solana_program::system_instruction::transfer(
             &stake_vault.key(),
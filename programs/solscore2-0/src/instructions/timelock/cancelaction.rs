@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::timelock::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(
+        mut,
+        constraint = timelock.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"pending-action", &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+        constraint = !pending_action.executed @ ErrorCode::ActionAlreadyExecuted,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+}
+
+pub fn cancel_action(_ctx: Context<CancelAction>) -> Result<()> {
+    // Closing the pending action is the cancellation
+    Ok(())
+}
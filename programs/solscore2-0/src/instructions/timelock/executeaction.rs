@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::timelock::*;
+use crate::state::treasury::*;
+use crate::state::reward::*;
+use crate::state::stake::*;
+use crate::state::protocol::*;
+use crate::instructions::treasury::*;
+use crate::errors::*;
+use crate::instructions::timelock::validate_action;
+
+// Permissionless once the delay has passed
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending-action", &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+        constraint = !pending_action.executed @ ErrorCode::ActionAlreadyExecuted,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-config"],
+        bump = reward_config.bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake-config"],
+        bump = stake_config.bump,
+    )]
+    pub stake_config: Account<'info, StakeConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    // Required for swap venue changes
+    #[account(
+        mut,
+        seeds = [b"buyback-config"],
+        bump = buyback_config.bump,
+    )]
+    pub buyback_config: Option<Account<'info, BuybackConfig>>,
+    
+    #[account(mut)]
+    /// CHECK: Checked against the action for withdrawals, unused otherwise
+    pub recipient: UncheckedAccount<'info>,
+    
+    // SPL withdrawals and mint setting changes only
+    #[account(
+        mut,
+        seeds = [b"treasury-mint", treasury_mint.mint.as_ref()],
+        bump = treasury_mint.bump,
+    )]
+    pub treasury_mint: Option<Account<'info, TreasuryMint>>,
+    
+    #[account(mut)]
+    pub token_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+    let timelock = &mut ctx.accounts.timelock;
+    let pending_action = &mut ctx.accounts.pending_action;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let recipient = &ctx.accounts.recipient;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    check_eta(pending_action.eta, current_time)?;
+    
+    // Parameters may have been valid when queued under different rules
+    validate_action(&pending_action.action)?;
+    
+    pending_action.executed = true;
+    
+    match pending_action.action.clone() {
+        QueuedAction::UpdateTreasuryConfig { update } => {
+            update_treasury_config(treasury, &update, current_time)?;
+        }
+        QueuedAction::UpdateTreasuryMint { mint, update } => {
+            let treasury_mint = ctx.accounts.treasury_mint
+                .as_mut()
+                .ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+            if treasury_mint.mint != mint {
+                return Err(error!(ErrorCode::InvalidTreasuryMint));
+            }
+            update_treasury_mint(treasury_mint, &update, current_time)?;
+        }
+        QueuedAction::UpdateRewardApy { base_apy } => {
+            ctx.accounts.reward_config.base_apy = base_apy;
+        }
+        QueuedAction::UpdateStakeFee { early_withdrawal_fee } => {
+            ctx.accounts.stake_config.early_withdrawal_fee = early_withdrawal_fee;
+        }
+        QueuedAction::UpdateTimelock { delay, large_withdrawal_threshold } => {
+            timelock.delay = delay;
+            timelock.large_withdrawal_threshold = large_withdrawal_threshold;
+        }
+        QueuedAction::Withdraw { amount, recipient: queued_recipient, mint } => {
+            // The multisig replaces the admin for withdrawals once enabled
+            if treasury.multisig_enabled {
+                return Err(error!(ErrorCode::MultisigRequired));
+            }
+            
+            if ctx.accounts.protocol_state.paused.treasury {
+                return Err(error!(ErrorCode::ProtocolPaused));
+            }
+            
+            if recipient.key() != queued_recipient {
+                return Err(error!(ErrorCode::UnauthorizedAccess));
+            }
+            
+            // SPL withdrawals are paid to a token account owned by the recipient
+            if let Some(mint) = mint {
+                let recipient_token_account = ctx.accounts.recipient_token_account.as_ref();
+                if recipient_token_account.map_or(false, |account| account.owner != queued_recipient) {
+                    return Err(error!(ErrorCode::UnauthorizedAccess));
+                }
+                
+                return withdraw_from_token_vault(
+                    treasury,
+                    ctx.accounts.treasury_mint.as_mut(),
+                    ctx.accounts.token_vault.as_ref(),
+                    recipient_token_account,
+                    ctx.accounts.token_program.as_ref(),
+                    mint,
+                    amount,
+                );
+            }
+            
+            // Validate against the reserve and update the ledger
+            treasury.record_withdrawal(current_time, treasury_vault.lamports(), amount)?;
+            
+            // Transfer SOL from treasury vault to recipient
+            let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+            let seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+            let signer = &[&seeds[..]];
+            
+            anchor_lang::solana_program::program::invoke_signed(
+                &anchor_lang::solana_program::system_instruction::transfer(
+                    &treasury_vault.key(),
+                    &recipient.key(),
+                    amount,
+                ),
+                &[
+                    treasury_vault.to_account_info(),
+                    recipient.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                signer,
+            )?;
+        }
+        QueuedAction::Unpause { flags } => {
+            ctx.accounts.protocol_state.paused.unpause(&flags);
+        }
+        QueuedAction::SetGuardian { guardian } => {
+            ctx.accounts.protocol_state.guardian = guardian;
+        }
+        QueuedAction::SetSwapPrograms { swap_programs } => {
+            let buyback_config = ctx.accounts.buyback_config
+                .as_mut()
+                .ok_or(error!(ErrorCode::BuybackConfigRequired))?;
+            buyback_config.swap_programs = swap_programs;
+        }
+        QueuedAction::SetBuybackRate { reference_rate } => {
+            let buyback_config = ctx.accounts.buyback_config
+                .as_mut()
+                .ok_or(error!(ErrorCode::BuybackConfigRequired))?;
+            buyback_config.reference_rate = reference_rate;
+        }
+        QueuedAction::CreateBudget { .. } => {
+            // Consumed by `create_budget`, which creates the budget account
+            return Err(error!(ErrorCode::ApprovalMismatch));
+        }
+    }
+    
+    Ok(())
+}
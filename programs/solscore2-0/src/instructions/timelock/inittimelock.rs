@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::timelock::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeTimelock<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 8 + 8 + 8 + 1, // Adjust space calculation
+        seeds = [b"timelock"],
+        bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_timelock(
+    ctx: Context<InitializeTimelock>,
+    delay: i64,
+    large_withdrawal_threshold: u64,
+) -> Result<()> {
+    let timelock = &mut ctx.accounts.timelock;
+    let bump = *ctx.bumps.get("timelock").unwrap();
+    
+    // Validate parameters
+    if delay < MIN_TIMELOCK_DELAY {
+        return Err(error!(ErrorCode::InvalidTimelockParameter));
+    }
+    
+    timelock.admin = ctx.accounts.admin.key();
+    timelock.delay = delay;
+    timelock.large_withdrawal_threshold = large_withdrawal_threshold;
+    timelock.action_count = 0;
+    timelock.bump = bump;
+    
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod inittimelock;
+pub mod queueaction;
+pub mod cancelaction;
+pub mod executeaction;
+
+pub use inittimelock::*;
+pub use queueaction::*;
+pub use cancelaction::*;
+pub use executeaction::*;
\ No newline at end of file
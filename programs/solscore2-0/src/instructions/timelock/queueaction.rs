@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::timelock::*;
+use crate::state::treasury::*;
+use crate::instructions::treasury::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(
+        mut,
+        constraint = timelock.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 8 + (1 + 4 + MAX_SWAP_VENUES * 32) + 8 + 8 + 1 + 1, // Adjust space calculation
+        seeds = [b"pending-action", &timelock.action_count.to_le_bytes()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn queue_action(ctx: Context<QueueAction>, action: QueuedAction) -> Result<()> {
+    let timelock = &mut ctx.accounts.timelock;
+    let pending_action = &mut ctx.accounts.pending_action;
+    let bump = *ctx.bumps.get("pending_action").unwrap();
+    
+    // Reject bad parameters up front rather than after the delay
+    validate_action(&action)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    pending_action.id = timelock.action_count;
+    pending_action.action = action;
+    pending_action.queued_at = current_time;
+    pending_action.eta = current_time + timelock.delay;
+    pending_action.executed = false;
+    pending_action.bump = bump;
+    
+    timelock.action_count += 1;
+    
+    Ok(())
+}
+
+// Helper function to validate a queued action's parameters
+pub(crate) fn validate_action(action: &QueuedAction) -> Result<()> {
+    match action {
+        QueuedAction::UpdateTreasuryConfig { update } => validate_treasury_config(update)?,
+        QueuedAction::UpdateTreasuryMint { update, .. } => validate_treasury_mint_update(update)?,
+        QueuedAction::UpdateRewardApy { base_apy } => {
+            if *base_apy > 100 {
+                return Err(error!(ErrorCode::InvalidRewardParameter));
+            }
+        }
+        QueuedAction::UpdateStakeFee { early_withdrawal_fee } => {
+            if *early_withdrawal_fee > 100 {
+                return Err(error!(ErrorCode::InvalidStakeParameter));
+            }
+        }
+        QueuedAction::UpdateTimelock { delay, .. } => {
+            if *delay < MIN_TIMELOCK_DELAY {
+                return Err(error!(ErrorCode::InvalidTimelockParameter));
+            }
+        }
+        QueuedAction::Withdraw { amount, .. } => {
+            if *amount == 0 {
+                return Err(error!(ErrorCode::InvalidWithdrawalAmount));
+            }
+        }
+        QueuedAction::Unpause { flags } => {
+            if flags.is_empty() {
+                return Err(error!(ErrorCode::InvalidPauseFlags));
+            }
+        }
+        QueuedAction::SetGuardian { .. } => {}
+        QueuedAction::SetSwapPrograms { swap_programs } => {
+            BuybackConfig::validate_swap_programs(swap_programs)?;
+        }
+        QueuedAction::SetBuybackRate { reference_rate } => {
+            if *reference_rate == 0 {
+                return Err(error!(ErrorCode::InvalidTreasuryParameter));
+            }
+        }
+        QueuedAction::CreateBudget { name, allocation } => validate_budget(name, *allocation)?,
+    }
+    
+    Ok(())
+}
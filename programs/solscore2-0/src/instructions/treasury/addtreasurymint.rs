@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TreasuryMintParams {
+    pub reserve_percentage: u8,
+    pub large_withdrawal_threshold: u64,
+    pub outflow_cap: u64,
+    pub outflow_cap_percentage: u8,
+    pub outflow_window: i64,
+}
+
+#[derive(Accounts)]
+pub struct AddTreasuryMint<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 32 + 8 + 1 + 8 + OutflowLimit::SIZE + 1, // Adjust space calculation
+        seeds = [b"treasury-mint", mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_mint: Account<'info, TreasuryMint>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"treasury-token-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury,
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn add_treasury_mint(ctx: Context<AddTreasuryMint>, params: TreasuryMintParams) -> Result<()> {
+    let treasury_mint = &mut ctx.accounts.treasury_mint;
+    let bump = *ctx.bumps.get("treasury_mint").unwrap();
+    
+    // Validate parameters
+    if params.reserve_percentage > 100 {
+        return Err(error!(ErrorCode::InvalidTreasuryParameter));
+    }
+    
+    treasury_mint.mint = ctx.accounts.mint.key();
+    treasury_mint.vault = ctx.accounts.token_vault.key();
+    treasury_mint.total_fees = 0;
+    treasury_mint.reserve_percentage = params.reserve_percentage;
+    treasury_mint.large_withdrawal_threshold = params.large_withdrawal_threshold;
+    treasury_mint.outflow_limit = OutflowLimit::new(
+        params.outflow_cap,
+        params.outflow_cap_percentage,
+        params.outflow_window,
+    )?;
+    treasury_mint.bump = bump;
+    
+    Ok(())
+}
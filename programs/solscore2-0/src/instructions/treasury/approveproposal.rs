@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        constraint = multisig.is_signer(&signer.key()) @ ErrorCode::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, TreasuryMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let signer = &ctx.accounts.signer;
+    
+    if proposal.executed {
+        return Err(error!(ErrorCode::ProposalAlreadyExecuted));
+    }
+    
+    if proposal.cancelled {
+        return Err(error!(ErrorCode::ProposalCancelled));
+    }
+    
+    if Clock::get()?.unix_timestamp >= proposal.expires_at {
+        return Err(error!(ErrorCode::ProposalExpired));
+    }
+    
+    if proposal.signer_set_seqno != multisig.signer_set_seqno {
+        return Err(error!(ErrorCode::StaleProposal));
+    }
+    
+    if proposal.approvals.contains(&signer.key()) {
+        return Err(error!(ErrorCode::AlreadyApproved));
+    }
+    
+    proposal.approvals.push(signer.key());
+    
+    Ok(())
+}
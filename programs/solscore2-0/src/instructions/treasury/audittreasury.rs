@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TreasuryAudit {
+    pub vault_balance: u64,
+    pub rent_reserve: u64,
+    pub recorded_fees: u64,
+    pub surplus: u64,
+}
+
+// Permissionless view, compares the vault balance against the treasury ledger
+#[derive(Accounts)]
+pub struct AuditTreasury<'info> {
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+}
+
+pub fn audit_treasury(ctx: Context<AuditTreasury>) -> Result<TreasuryAudit> {
+    let treasury = &ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    
+    // The vault keeps its own rent-exempt minimum on top of the fees
+    let vault_balance = treasury_vault.lamports();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_vault.data_len());
+    
+    // Anyone can send lamports to the vault, so only a shortfall is an error
+    let held = vault_balance.saturating_sub(rent_reserve);
+    if held < treasury.total_fees {
+        return Err(error!(ErrorCode::TreasuryOutOfBalance));
+    }
+    
+    Ok(TreasuryAudit {
+        vault_balance,
+        rent_reserve,
+        recorded_fees: treasury.total_fees,
+        surplus: held - treasury.total_fees,
+    })
+}
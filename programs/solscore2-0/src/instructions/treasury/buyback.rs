@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::treasury::*;
+use crate::state::timelock::*;
+use crate::swap::*;
+use crate::events::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ExecuteBuyback<'info> {
+    #[account(
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = !treasury.multisig_enabled @ ErrorCode::MultisigRequired,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"buyback-config"],
+        bump = buyback_config.bump,
+    )]
+    pub buyback_config: Account<'info, BuybackConfig>,
+    
+    #[account(
+        mut,
+        address = buyback_config.token_mint @ ErrorCode::InvalidTreasuryMint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        address = buyback_config.token_vault @ ErrorCode::InvalidTreasuryMint,
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: Must be a whitelisted swap venue, its accounts are passed as remaining accounts
+    #[account(
+        executable,
+        constraint = buyback_config.is_whitelisted(&swap_program.key()) @ ErrorCode::UnsupportedSwapProgram,
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_buyback<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteBuyback<'info>>,
+    amount_in: u64,
+    selector: [u8; 8],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    if amount_in == 0 {
+        return Err(error!(ErrorCode::InvalidWithdrawalAmount));
+    }
+    
+    // Buybacks spend treasury SOL, large ones are held to the same timelock as withdrawals
+    if amount_in >= ctx.accounts.timelock.large_withdrawal_threshold {
+        return Err(error!(ErrorCode::RequiresTimelock));
+    }
+    
+    // Per-epoch spend cap, the counter resets on a new epoch
+    let buyback_config = &mut ctx.accounts.buyback_config;
+    if buyback_config.current_epoch != clock.epoch {
+        buyback_config.current_epoch = clock.epoch;
+        buyback_config.epoch_spent = 0;
+    }
+    if buyback_config.epoch_spent + amount_in > buyback_config.epoch_cap {
+        return Err(error!(ErrorCode::BuybackCapExceeded));
+    }
+    
+    // The floor comes from the configured rate, not from the caller
+    let min_amount_out = buyback_config.min_amount_out(amount_in);
+    if min_amount_out == 0 {
+        return Err(error!(ErrorCode::InvalidWithdrawalAmount));
+    }
+    
+    // Swap SOL from the treasury vault into the buyback token vault
+    let vault_balance = ctx.accounts.treasury_vault.lamports();
+    let balance_before = ctx.accounts.token_vault.amount;
+    
+    let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+    let vault_seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    let adapter = RouteAdapter {
+        swap_program: ctx.accounts.swap_program.to_account_info(),
+        payer: ctx.accounts.treasury_vault.key(),
+        destination: ctx.accounts.token_vault.key(),
+        accounts: ctx.remaining_accounts,
+        selector,
+    };
+    adapter.swap_sol_for_token(amount_in, min_amount_out, vault_signer)?;
+    
+    // Don't trust the swap program, check what actually left the vault and what arrived
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    if *treasury_vault.owner != anchor_lang::system_program::ID || !treasury_vault.data_is_empty() {
+        return Err(error!(ErrorCode::UnsupportedSwapProgram));
+    }
+    let sol_spent = vault_balance.saturating_sub(treasury_vault.lamports());
+    if sol_spent > amount_in {
+        return Err(error!(ErrorCode::SlippageExceeded));
+    }
+    
+    ctx.accounts.token_vault.reload()?;
+    let tokens_received = ctx.accounts.token_vault.amount - balance_before;
+    if tokens_received < min_amount_out {
+        return Err(error!(ErrorCode::SlippageExceeded));
+    }
+    
+    // Buybacks are treasury outflows like any other
+    ctx.accounts.treasury.record_withdrawal(clock.unix_timestamp, vault_balance, sol_spent)?;
+    
+    // Burn the bought tokens, signed by the treasury PDA
+    let treasury_bump = ctx.accounts.treasury.bump;
+    let treasury_seeds = &[b"treasury".as_ref(), &[treasury_bump]];
+    let treasury_signer = &[&treasury_seeds[..]];
+    
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            treasury_signer,
+        ),
+        tokens_received,
+    )?;
+    
+    let buyback_config = &mut ctx.accounts.buyback_config;
+    buyback_config.epoch_spent += sol_spent;
+    buyback_config.total_burned += tokens_received;
+    
+    emit!(BuybackExecuted {
+        sol_spent,
+        tokens_burned: tokens_received,
+        epoch: clock.epoch,
+    });
+    
+    Ok(())
+}
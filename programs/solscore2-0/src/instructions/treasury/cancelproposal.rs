@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+// Signers vote to cancel, the proposal is cancelled once the votes reach the threshold.
+// Queued proposals can be cancelled until they execute, even after `expires_at`.
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        constraint = multisig.is_signer(&signer.key()) @ ErrorCode::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, TreasuryMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let signer = &ctx.accounts.signer;
+    
+    if proposal.executed {
+        return Err(error!(ErrorCode::ProposalAlreadyExecuted));
+    }
+    
+    if proposal.cancelled {
+        return Err(error!(ErrorCode::ProposalCancelled));
+    }
+    
+    if proposal.cancellations.contains(&signer.key()) {
+        return Err(error!(ErrorCode::AlreadyApproved));
+    }
+    
+    proposal.cancellations.push(signer.key());
+    
+    // Only votes from the current signer set count
+    if multisig.count_signers(&proposal.cancellations) >= multisig.threshold as usize {
+        proposal.cancelled = true;
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::budget::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+// Stops the stream now; what has vested stays withdrawable, the rest returns to the budget
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"budget", budget.name.as_bytes()],
+        bump = budget.bump,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"stream", budget.key().as_ref(), &stream.id.to_le_bytes()],
+        bump = stream.bump,
+        constraint = !stream.cancelled @ ErrorCode::StreamCancelled,
+    )]
+    pub stream: Account<'info, GrantStream>,
+}
+
+pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+    let budget = &mut ctx.accounts.budget;
+    let stream = &mut ctx.accounts.stream;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let vested = stream.vested_amount(current_time);
+    let unvested = stream.total_amount - vested;
+    
+    // Freeze the stream at what has vested so far
+    stream.total_amount = vested;
+    stream.end_time = current_time.max(stream.start_time);
+    stream.cancelled = true;
+    
+    budget.committed -= unvested;
+    
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::state::budget::*;
+use crate::state::treasury::*;
+use crate::state::timelock::*;
+use crate::instructions::treasury::check_proposal_ready;
+use crate::errors::*;
+
+// Budgets reserve their allocation on the treasury ledger. The admin creates them
+// directly, or through a queued `CreateBudget` action at or above the large withdrawal
+// threshold. Once multisig is enabled they need an approved `CreateBudget` proposal.
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateBudget<'info> {
+    // Treasury admin, or a multisig signer once multisig is enabled
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + (4 + MAX_BUDGET_NAME_LEN) + 8 + 8 + 8 + 8 + 1, // Adjust space calculation
+        seeds = [b"budget", name.as_bytes()],
+        bump
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    // Required for large allocations without a multisig
+    #[account(
+        mut,
+        seeds = [b"pending-action", &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+        constraint = !pending_action.executed @ ErrorCode::ActionAlreadyExecuted,
+    )]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+    
+    // Required when multisig is enabled
+    #[account(
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Option<Account<'info, TreasuryMultisig>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.multisig.as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Option<Account<'info, MultisigProposal>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_budget(ctx: Context<CreateBudget>, name: String, allocation: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let budget = &mut ctx.accounts.budget;
+    let bump = *ctx.bumps.get("budget").unwrap();
+    
+    validate_budget(&name, allocation)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // The threshold applies to the whole allocation, however it is later split into streams
+    let large = allocation >= ctx.accounts.timelock.large_withdrawal_threshold;
+    
+    if treasury.multisig_enabled {
+        let proposal = authorize_proposal(
+            &ctx.accounts.authority,
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            ProposalKind::CreateBudget { name: name.clone(), allocation },
+            current_time,
+        )?;
+        
+        // Large allocations are queued by `execute_proposal` first
+        if large {
+            check_eta(proposal.eta, current_time)?;
+        }
+    } else {
+        if treasury.admin != ctx.accounts.authority.key() {
+            return Err(error!(ErrorCode::UnauthorizedAccess));
+        }
+        
+        if large {
+            let pending_action = ctx.accounts.pending_action
+                .as_mut()
+                .ok_or(error!(ErrorCode::RequiresTimelock))?;
+            match &pending_action.action {
+                QueuedAction::CreateBudget { name: queued_name, allocation: queued_allocation }
+                    if *queued_name == name && *queued_allocation == allocation => {}
+                _ => return Err(error!(ErrorCode::ApprovalMismatch)),
+            }
+            check_eta(pending_action.eta, current_time)?;
+            
+            pending_action.executed = true;
+        }
+    }
+    
+    // Reserve the allocation so it can't be withdrawn from under the budget
+    treasury.reserve_budget(allocation)?;
+    
+    budget.name = name;
+    budget.allocation = allocation;
+    budget.committed = 0;
+    budget.spent = 0;
+    budget.stream_count = 0;
+    budget.bump = bump;
+    
+    Ok(())
+}
+
+// Helper function to validate a budget's parameters
+pub(crate) fn validate_budget(name: &str, allocation: u64) -> Result<()> {
+    if name.is_empty() || name.len() > MAX_BUDGET_NAME_LEN || allocation == 0 {
+        return Err(error!(ErrorCode::InvalidBudgetParameter));
+    }
+    
+    Ok(())
+}
+
+// Helper function to consume an approved multisig proposal for a budget or stream action
+pub(crate) fn authorize_proposal<'a, 'info>(
+    authority: &Signer<'info>,
+    multisig: Option<&Account<'info, TreasuryMultisig>>,
+    proposal: Option<&'a mut Account<'info, MultisigProposal>>,
+    kind: ProposalKind,
+    now: i64,
+) -> Result<&'a mut Account<'info, MultisigProposal>> {
+    let multisig = multisig.ok_or(error!(ErrorCode::MultisigRequired))?;
+    let proposal = proposal.ok_or(error!(ErrorCode::MultisigRequired))?;
+    
+    if !multisig.is_signer(&authority.key()) {
+        return Err(error!(ErrorCode::NotMultisigSigner));
+    }
+    
+    if proposal.multisig != multisig.key() || proposal.kind != kind {
+        return Err(error!(ErrorCode::ApprovalMismatch));
+    }
+    
+    check_proposal_ready(multisig, proposal, now)?;
+    proposal.executed = true;
+    
+    Ok(proposal)
+}
\ No newline at end of file
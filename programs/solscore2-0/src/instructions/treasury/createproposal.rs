@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::instructions::treasury::{validate_budget, validate_stream};
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        constraint = multisig.is_signer(&proposer.key()) @ ErrorCode::NotMultisigSigner,
+    )]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, TreasuryMultisig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + 32 + 32 + 8 + (1 + 4 + 32 * MAX_MULTISIG_SIGNERS + 1) + (4 + 32 * MAX_MULTISIG_SIGNERS) + 4 + 8 + 8 + 1 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1 + 1, // Adjust space calculation
+        seeds = [b"proposal", multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    kind: ProposalKind,
+    expires_in: i64,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = &ctx.accounts.proposer;
+    let bump = *ctx.bumps.get("proposal").unwrap();
+    
+    // Validate parameters
+    if expires_in <= 0 || expires_in > MAX_PROPOSAL_LIFETIME {
+        return Err(error!(ErrorCode::InvalidMultisigConfig));
+    }
+    
+    match &kind {
+        ProposalKind::Withdraw { amount, memo, .. } => {
+            if *amount == 0 {
+                return Err(error!(ErrorCode::InvalidWithdrawalAmount));
+            }
+            if memo.len() > MAX_PROPOSAL_MEMO_LEN {
+                return Err(error!(ErrorCode::InvalidMultisigConfig));
+            }
+        }
+        ProposalKind::ChangeSigners { signers, threshold } => {
+            TreasuryMultisig::validate_signers(signers, *threshold)?;
+        }
+        ProposalKind::CreateBudget { name, allocation } => validate_budget(name, *allocation)?,
+        ProposalKind::CreateStream { amount, duration, .. } => validate_stream(*amount, *duration)?,
+    }
+    
+    proposal.multisig = multisig.key();
+    proposal.proposer = proposer.key();
+    proposal.id = multisig.proposal_count;
+    proposal.kind = kind;
+    proposal.approvals = vec![proposer.key()]; // Proposing counts as approving
+    proposal.signer_set_seqno = multisig.signer_set_seqno;
+    proposal.expires_at = Clock::get()?.unix_timestamp + expires_in;
+    proposal.eta = 0;
+    proposal.executed = false;
+    proposal.cancellations = Vec::new();
+    proposal.cancelled = false;
+    proposal.bump = bump;
+    
+    multisig.proposal_count += 1;
+    
+    Ok(())
+}
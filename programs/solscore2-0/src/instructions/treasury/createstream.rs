@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::budget::*;
+use crate::state::treasury::*;
+use crate::instructions::treasury::authorize_proposal;
+use crate::errors::*;
+
+// Streams commit part of a budget's allocation, the timelock threshold was already
+// applied to the allocation as a whole. Once multisig is enabled they need an approved
+// `CreateStream` proposal.
+#[derive(Accounts)]
+pub struct CreateStream<'info> {
+    // Treasury admin, or a multisig signer once multisig is enabled
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"budget", budget.name.as_bytes()],
+        bump = budget.bump,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1, // Adjust space calculation
+        seeds = [b"stream", budget.key().as_ref(), &budget.stream_count.to_le_bytes()],
+        bump
+    )]
+    pub stream: Account<'info, GrantStream>,
+    
+    // Required when multisig is enabled
+    #[account(
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Option<Account<'info, TreasuryMultisig>>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.multisig.as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Option<Account<'info, MultisigProposal>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_stream(
+    ctx: Context<CreateStream>,
+    recipient: Pubkey,
+    amount: u64,
+    start_time: i64,
+    duration: i64,
+) -> Result<()> {
+    let budget = &mut ctx.accounts.budget;
+    let stream = &mut ctx.accounts.stream;
+    let bump = *ctx.bumps.get("stream").unwrap();
+    
+    validate_stream(amount, duration)?;
+    
+    if ctx.accounts.treasury.multisig_enabled {
+        authorize_proposal(
+            &ctx.accounts.authority,
+            ctx.accounts.multisig.as_ref(),
+            ctx.accounts.proposal.as_mut(),
+            ProposalKind::CreateStream { budget: budget.key(), recipient, amount, start_time, duration },
+            Clock::get()?.unix_timestamp,
+        )?;
+    } else if ctx.accounts.treasury.admin != ctx.accounts.authority.key() {
+        return Err(error!(ErrorCode::UnauthorizedAccess));
+    }
+    
+    // Streams can't commit more than the budget allocation
+    if budget.committed + amount > budget.allocation {
+        return Err(error!(ErrorCode::ExceedsBudget));
+    }
+    
+    stream.budget = budget.key();
+    stream.recipient = recipient;
+    stream.id = budget.stream_count;
+    stream.total_amount = amount;
+    stream.withdrawn_amount = 0;
+    stream.start_time = start_time;
+    stream.end_time = start_time + duration;
+    stream.cancelled = false;
+    stream.bump = bump;
+    
+    budget.committed += amount;
+    budget.stream_count += 1;
+    
+    Ok(())
+}
+
+// Helper function to validate a stream's parameters
+pub(crate) fn validate_stream(amount: u64, duration: i64) -> Result<()> {
+    if amount == 0 || duration <= 0 {
+        return Err(error!(ErrorCode::InvalidBudgetParameter));
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::treasury::*;
+use crate::state::timelock::*;
+use crate::state::protocol::*;
+use crate::instructions::treasury::withdraw_from_token_vault;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        constraint = multisig.is_signer(&executor.key()) @ ErrorCode::NotMultisigSigner,
+    )]
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-multisig"],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, TreasuryMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    /// CHECK: Checked against the proposal for withdrawals, unused for signer changes
+    pub recipient: UncheckedAccount<'info>,
+    
+    // SPL withdrawals only
+    #[account(
+        mut,
+        seeds = [b"treasury-mint", treasury_mint.mint.as_ref()],
+        bump = treasury_mint.bump,
+    )]
+    pub treasury_mint: Option<Account<'info, TreasuryMint>>,
+    
+    #[account(mut)]
+    pub token_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let recipient = &ctx.accounts.recipient;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    check_proposal_ready(multisig, proposal, current_time)?;
+    
+    match proposal.kind.clone() {
+        ProposalKind::Withdraw { amount, recipient: proposed_recipient, mint, .. } => {
+            if recipient.key() != proposed_recipient {
+                return Err(error!(ErrorCode::UnauthorizedAccess));
+            }
+            
+            // SPL withdrawals are paid to a token account owned by the recipient
+            let recipient_token_account = ctx.accounts.recipient_token_account.as_ref();
+            if recipient_token_account.map_or(false, |account| account.owner != proposed_recipient) {
+                return Err(error!(ErrorCode::UnauthorizedAccess));
+            }
+            
+            let large_withdrawal_threshold = match mint {
+                None => ctx.accounts.timelock.large_withdrawal_threshold,
+                Some(_) => ctx.accounts.treasury_mint
+                    .as_ref()
+                    .map(|treasury_mint| treasury_mint.large_withdrawal_threshold)
+                    .ok_or(error!(ErrorCode::TokenAccountsRequired))?,
+            };
+            
+            // Large withdrawals wait out the timelock delay once approved,
+            // the first execution only queues them
+            if amount >= large_withdrawal_threshold {
+                if proposal.eta == 0 {
+                    proposal.eta = current_time + ctx.accounts.timelock.delay;
+                    return Ok(());
+                }
+                
+                check_eta(proposal.eta, current_time)?;
+            }
+            
+            proposal.executed = true;
+            
+            if let Some(mint) = mint {
+                return withdraw_from_token_vault(
+                    treasury,
+                    ctx.accounts.treasury_mint.as_mut(),
+                    ctx.accounts.token_vault.as_ref(),
+                    recipient_token_account,
+                    ctx.accounts.token_program.as_ref(),
+                    mint,
+                    amount,
+                );
+            }
+            
+            // Validate against the reserve and update the ledger
+            treasury.record_withdrawal(current_time, treasury_vault.lamports(), amount)?;
+            
+            // Transfer SOL from treasury vault to recipient
+            let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+            let seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+            let signer = &[&seeds[..]];
+            
+            anchor_lang::solana_program::program::invoke_signed(
+                &anchor_lang::solana_program::system_instruction::transfer(
+                    &treasury_vault.key(),
+                    &recipient.key(),
+                    amount,
+                ),
+                &[
+                    treasury_vault.to_account_info(),
+                    recipient.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                signer,
+            )?;
+        }
+        ProposalKind::ChangeSigners { signers, threshold } => {
+            proposal.executed = true;
+            
+            // Re-validated in case the proposal predates a config change
+            TreasuryMultisig::validate_signers(&signers, threshold)?;
+            
+            multisig.signers = signers;
+            multisig.threshold = threshold;
+            
+            // Invalidates every other outstanding proposal
+            multisig.signer_set_seqno += 1;
+        }
+        ProposalKind::CreateBudget { allocation, .. } => {
+            // Consumed by `create_budget`, large allocations are queued here first
+            if allocation < ctx.accounts.timelock.large_withdrawal_threshold || proposal.eta != 0 {
+                return Err(error!(ErrorCode::ApprovalMismatch));
+            }
+            proposal.eta = current_time + ctx.accounts.timelock.delay;
+        }
+        ProposalKind::CreateStream { .. } => {
+            // Consumed by `create_stream`
+            return Err(error!(ErrorCode::ApprovalMismatch));
+        }
+    }
+    
+    Ok(())
+}
+
+// Helper function to check a proposal is live and approved by the current signer set
+pub(crate) fn check_proposal_ready(multisig: &TreasuryMultisig, proposal: &MultisigProposal, now: i64) -> Result<()> {
+    if proposal.executed {
+        return Err(error!(ErrorCode::ProposalAlreadyExecuted));
+    }
+    
+    if proposal.cancelled {
+        return Err(error!(ErrorCode::ProposalCancelled));
+    }
+    
+    // Queued proposals expire with the timelock grace period instead
+    if proposal.eta == 0 && now >= proposal.expires_at {
+        return Err(error!(ErrorCode::ProposalExpired));
+    }
+    
+    if proposal.signer_set_seqno != multisig.signer_set_seqno {
+        return Err(error!(ErrorCode::StaleProposal));
+    }
+    
+    // Only approvals from the current signer set count
+    if multisig.count_signers(&proposal.approvals) < multisig.threshold as usize {
+        return Err(error!(ErrorCode::ThresholdNotMet));
+    }
+    
+    Ok(())
+}
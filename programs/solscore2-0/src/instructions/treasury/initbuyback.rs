@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::treasury::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuybackParams {
+    pub reference_rate: u64,
+    pub max_slippage_bps: u16,
+    pub epoch_cap: u64,
+    pub swap_programs: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBuyback<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub token_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = BuybackConfig::SPACE,
+        seeds = [b"buyback-config"],
+        bump
+    )]
+    pub buyback_config: Account<'info, BuybackConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"buyback-vault", token_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = treasury,
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_buyback(ctx: Context<InitializeBuyback>, params: BuybackParams) -> Result<()> {
+    let buyback_config = &mut ctx.accounts.buyback_config;
+    let bump = *ctx.bumps.get("buyback_config").unwrap();
+    
+    // Validate parameters
+    if params.reference_rate == 0 || params.max_slippage_bps > 10_000 || params.epoch_cap == 0 {
+        return Err(error!(ErrorCode::InvalidTreasuryParameter));
+    }
+    
+    BuybackConfig::validate_swap_programs(&params.swap_programs)?;
+    
+    buyback_config.token_mint = ctx.accounts.token_mint.key();
+    buyback_config.token_vault = ctx.accounts.token_vault.key();
+    buyback_config.swap_programs = params.swap_programs;
+    buyback_config.reference_rate = params.reference_rate;
+    buyback_config.max_slippage_bps = params.max_slippage_bps;
+    buyback_config.epoch_cap = params.epoch_cap;
+    buyback_config.current_epoch = Clock::get()?.epoch;
+    buyback_config.epoch_spent = 0;
+    buyback_config.total_burned = 0;
+    buyback_config.bump = bump;
+    
+    Ok(())
+}
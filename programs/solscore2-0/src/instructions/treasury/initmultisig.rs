@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::errors::*;
+
+// Hands treasury withdrawals over to an M-of-N signer set
+#[derive(Accounts)]
+pub struct InitializeMultisig<'info> {
+    #[account(
+        mut,
+        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1 + 4 + 8 + 1, // Adjust space calculation
+        seeds = [b"treasury-multisig"],
+        bump
+    )]
+    pub multisig: Account<'info, TreasuryMultisig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_multisig(
+    ctx: Context<InitializeMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let multisig = &mut ctx.accounts.multisig;
+    let bump = *ctx.bumps.get("multisig").unwrap();
+    
+    // Validate parameters
+    TreasuryMultisig::validate_signers(&signers, threshold)?;
+    
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.signer_set_seqno = 0;
+    multisig.proposal_count = 0;
+    multisig.bump = bump;
+    
+    // Single-admin withdrawals are disabled from here on
+    treasury.multisig_enabled = true;
+    
+    Ok(())
+}
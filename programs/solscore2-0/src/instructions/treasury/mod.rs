@@ -2,8 +2,36 @@ pub mod treasuryinit;
 pub mod treasurydeposit;
 pub mod withdraw;
 pub mod updatetreasuryconfig;
+pub mod sweeptorewards;
+pub mod audittreasury;
+pub mod initmultisig;
+pub mod createproposal;
+pub mod approveproposal;
+pub mod executeproposal;
+pub mod cancelproposal;
+pub mod createbudget;
+pub mod createstream;
+pub mod withdrawstream;
+pub mod cancelstream;
+pub mod addtreasurymint;
+pub mod initbuyback;
+pub mod buyback;
 
 pub use treasuryinit::*;
 pub use treasurydeposit::*;
 pub use withdraw::*;
-pub use updatetreasuryconfig::*;
\ No newline at end of file
+pub use updatetreasuryconfig::*;
+pub use sweeptorewards::*;
+pub use audittreasury::*;
+pub use initmultisig::*;
+pub use createproposal::*;
+pub use approveproposal::*;
+pub use executeproposal::*;
+pub use cancelproposal::*;
+pub use createbudget::*;
+pub use createstream::*;
+pub use withdrawstream::*;
+pub use cancelstream::*;
+pub use addtreasurymint::*;
+pub use initbuyback::*;
+pub use buyback::*;
\ No newline at end of file
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::state::reward::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+// Permissionless, moves the accrued fee share from the treasury into the reward pool
+#[derive(Accounts)]
+pub struct SweepToRewards<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-pool"],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds rewards
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sweep_to_rewards(ctx: Context<SweepToRewards>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let reward_vault = &ctx.accounts.reward_vault;
+    
+    let amount = treasury.pending_rewards;
+    if amount == 0 {
+        return Err(error!(ErrorCode::NoRewardsAvailable));
+    }
+    
+    if amount > treasury_vault.lamports() {
+        return Err(error!(ErrorCode::InsufficientFunds));
+    }
+    
+    // Sweeps count towards the outflow limit like any other withdrawal
+    treasury.record_outflow(Clock::get()?.unix_timestamp, treasury_vault.lamports(), amount)?;
+    
+    // Move the share off the treasury ledger and onto the reward pool
+    treasury.total_fees = treasury.total_fees
+        .checked_sub(amount)
+        .ok_or(error!(ErrorCode::TreasuryOutOfBalance))?;
+    treasury.pending_rewards = 0;
+    reward_pool.total_rewards += amount;
+    
+    // Transfer SOL from treasury vault to reward vault
+    let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+    let seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &treasury_vault.key(),
+            &reward_vault.key(),
+            amount,
+        ),
+        &[
+            treasury_vault.to_account_info(),
+            reward_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
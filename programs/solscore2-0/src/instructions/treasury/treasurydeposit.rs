@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::treasury::*;
 use crate::errors::*;
 
@@ -22,19 +23,43 @@ pub struct DepositToTreasury<'info> {
     /// CHECK: This is the PDA that holds treasury funds
     pub treasury_vault: UncheckedAccount<'info>,
     
+    // SPL deposits only
+    #[account(
+        mut,
+        seeds = [b"treasury-mint", treasury_mint.mint.as_ref()],
+        bump = treasury_mint.bump,
+    )]
+    pub treasury_mint: Option<Account<'info, TreasuryMint>>,
+    
+    #[account(mut)]
+    pub token_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub depositor_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
-    let treasury = &mut ctx.accounts.treasury;
-    let depositor = &ctx.accounts.depositor;
-    let treasury_vault = &ctx.accounts.treasury_vault;
-    
+// `mint` of None deposits SOL, otherwise the given SPL mint
+pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64, mint: Option<Pubkey>) -> Result<()> {
     // Validate amount
     if amount == 0 {
         return Err(error!(ErrorCode::InvalidDepositAmount));
     }
     
+    match mint {
+        None => deposit_sol(ctx, amount),
+        Some(mint) => deposit_token(ctx, amount, mint),
+    }
+}
+
+fn deposit_sol(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let depositor = &ctx.accounts.depositor;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    
     // Transfer SOL to the treasury vault
     let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
         &depositor.key(),
@@ -50,8 +75,49 @@ pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Resu
         ],
     )?;
     
-    // Update treasury
-    treasury.total_fees += amount;
+    // Deposits are principal, not revenue, so the reward pool takes no share
+    treasury.credit_deposit(amount);
+    
+    Ok(())
+}
+
+fn deposit_token(ctx: Context<DepositToTreasury>, amount: u64, mint: Pubkey) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (treasury_mint, token_vault, depositor_token_account, token_program) = match (
+        accounts.treasury_mint.as_mut(),
+        accounts.token_vault.as_ref(),
+        accounts.depositor_token_account.as_ref(),
+        accounts.token_program.as_ref(),
+    ) {
+        (Some(treasury_mint), Some(token_vault), Some(depositor_token_account), Some(token_program)) => {
+            (treasury_mint, token_vault, depositor_token_account, token_program)
+        }
+        _ => return Err(error!(ErrorCode::TokenAccountsRequired)),
+    };
+    
+    // Accounts must all belong to the requested mint
+    if treasury_mint.mint != mint
+        || token_vault.key() != treasury_mint.vault
+        || depositor_token_account.mint != mint
+    {
+        return Err(error!(ErrorCode::InvalidTreasuryMint));
+    }
+    
+    // Transfer tokens to the treasury token vault
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: depositor_token_account.to_account_info(),
+                to: token_vault.to_account_info(),
+                authority: accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // Update the mint's fee ledger
+    treasury_mint.total_fees += amount;
     
     Ok(())
 }
\ No newline at end of file
@@ -5,6 +5,10 @@ use crate::errors::*;
 pub struct TreasuryParams {
     pub protocol_fee: u8,
     pub reserve_percentage: u8,
+    pub rewards_share: u8,
+    pub outflow_cap: u64,
+    pub outflow_cap_percentage: u8,
+    pub outflow_window: i64,
 }
 
 #[derive(Accounts)]
@@ -15,7 +19,7 @@ pub struct InitializeTreasury<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 8 + 1 + 1 + 1, // Adjust space calculation
+        space = 8 + 32 + 8 + 8 + 1 + 1 + 1 + 8 + 8 + 1 + OutflowLimit::SIZE + 1, // Adjust space calculation
         seeds = [b"treasury"],
         bump
     )]
@@ -39,14 +43,24 @@ pub fn initialize_treasury(ctx: Context<InitializeTreasury>, params: TreasuryPar
     let bump = *ctx.bumps.get("treasury").unwrap();
     
     // Validate parameters
-    if params.protocol_fee > 100 || params.reserve_percentage > 100 {
+    if params.protocol_fee > 100 || params.reserve_percentage > 100 || params.rewards_share > 100 {
         return Err(error!(ErrorCode::InvalidTreasuryParameter));
     }
     
     treasury.admin = ctx.accounts.admin.key();
     treasury.total_fees = 0;
+    treasury.total_deposits = 0;
     treasury.protocol_fee = params.protocol_fee;
     treasury.reserve_percentage = params.reserve_percentage;
+    treasury.rewards_share = params.rewards_share;
+    treasury.pending_rewards = 0;
+    treasury.budgeted = 0;
+    treasury.multisig_enabled = false;
+    treasury.outflow_limit = OutflowLimit::new(
+        params.outflow_cap,
+        params.outflow_cap_percentage,
+        params.outflow_window,
+    )?;
     treasury.bump = bump;
     
     Ok(())
@@ -2,43 +2,99 @@ use anchor_lang::prelude::*;
 use crate::state::treasury::*;
 use crate::errors::*;
 
-#[derive(Accounts)]
-pub struct UpdateTreasuryConfig<'info> {
-    #[account(
-        mut,
-        constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
-    )]
-    pub admin: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump,
-    )]
-    pub treasury: Account<'info, Treasury>,
+// Checked when the change is queued and again when it is applied
+pub fn validate_treasury_config(update: &TreasuryConfigUpdate) -> Result<()> {
+    let percentages = [
+        update.new_protocol_fee,
+        update.new_reserve_percentage,
+        update.new_rewards_share,
+        update.new_outflow_cap_percentage,
+    ];
+    
+    if percentages.iter().flatten().any(|value| *value > 100) {
+        return Err(error!(ErrorCode::InvalidTreasuryParameter));
+    }
+    
+    if let Some(window) = update.new_outflow_window {
+        if window <= 0 {
+            return Err(error!(ErrorCode::InvalidTreasuryParameter));
+        }
+    }
+    
+    Ok(())
 }
 
-pub fn update_treasury_config(
-    ctx: Context<UpdateTreasuryConfig>, 
-    new_protocol_fee: Option<u8>,
-    new_reserve_percentage: Option<u8>,
-) -> Result<()> {
-    let treasury = &mut ctx.accounts.treasury;
+// Applied through the timelock, see `execute_action`
+pub fn update_treasury_config(treasury: &mut Treasury, update: &TreasuryConfigUpdate, now: i64) -> Result<()> {
+    validate_treasury_config(update)?;
     
     // Update protocol fee if provided
-    if let Some(fee) = new_protocol_fee {
-        if fee > 100 {
-            return Err(error!(ErrorCode::InvalidTreasuryParameter));
-        }
+    if let Some(fee) = update.new_protocol_fee {
         treasury.protocol_fee = fee;
     }
     
     // Update reserve percentage if provided
-    if let Some(reserve) = new_reserve_percentage {
-        if reserve > 100 {
+    if let Some(reserve) = update.new_reserve_percentage {
+        treasury.reserve_percentage = reserve;
+    }
+    
+    // Update rewards share if provided
+    if let Some(share) = update.new_rewards_share {
+        treasury.rewards_share = share;
+    }
+    
+    // Update outflow limits if provided
+    if let Some(cap) = update.new_outflow_cap {
+        treasury.outflow_limit.cap = cap;
+    }
+    if let Some(percentage) = update.new_outflow_cap_percentage {
+        treasury.outflow_limit.cap_percentage = percentage;
+    }
+    if let Some(window) = update.new_outflow_window {
+        treasury.outflow_limit.set_window(now, window);
+    }
+    
+    Ok(())
+}
+
+// Checked when the change is queued and again when it is applied
+pub fn validate_treasury_mint_update(update: &TreasuryMintUpdate) -> Result<()> {
+    let percentages = [update.new_reserve_percentage, update.new_outflow_cap_percentage];
+    
+    if percentages.iter().flatten().any(|value| *value > 100) {
+        return Err(error!(ErrorCode::InvalidTreasuryParameter));
+    }
+    
+    if let Some(window) = update.new_outflow_window {
+        if window <= 0 {
             return Err(error!(ErrorCode::InvalidTreasuryParameter));
         }
-        treasury.reserve_percentage = reserve;
+    }
+    
+    Ok(())
+}
+
+// Applied through the timelock, see `execute_action`
+pub fn update_treasury_mint(treasury_mint: &mut TreasuryMint, update: &TreasuryMintUpdate, now: i64) -> Result<()> {
+    validate_treasury_mint_update(update)?;
+    
+    if let Some(reserve) = update.new_reserve_percentage {
+        treasury_mint.reserve_percentage = reserve;
+    }
+    
+    if let Some(threshold) = update.new_large_withdrawal_threshold {
+        treasury_mint.large_withdrawal_threshold = threshold;
+    }
+    
+    // Update outflow limits if provided
+    if let Some(cap) = update.new_outflow_cap {
+        treasury_mint.outflow_limit.cap = cap;
+    }
+    if let Some(percentage) = update.new_outflow_cap_percentage {
+        treasury_mint.outflow_limit.cap_percentage = percentage;
+    }
+    if let Some(window) = update.new_outflow_window {
+        treasury_mint.outflow_limit.set_window(now, window);
     }
     
     Ok(())
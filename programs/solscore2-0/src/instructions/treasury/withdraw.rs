@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::treasury::*;
+use crate::state::timelock::*;
+use crate::state::protocol::*;
 use crate::errors::*;
 
 #[derive(Accounts)]
@@ -7,6 +10,7 @@ pub struct WithdrawTreasury<'info> {
     #[account(
         mut,
         constraint = treasury.admin == admin.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = !treasury.multisig_enabled @ ErrorCode::MultisigRequired,
     )]
     pub admin: Signer<'info>,
     
@@ -17,6 +21,12 @@ pub struct WithdrawTreasury<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
     
+    #[account(
+        seeds = [b"timelock"],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+    
     #[account(
         mut,
         seeds = [b"treasury-vault"],
@@ -29,25 +39,55 @@ pub struct WithdrawTreasury<'info> {
     /// CHECK: This is where withdrawn funds will be sent
     pub recipient: UncheckedAccount<'info>,
     
+    // SPL withdrawals only
+    #[account(
+        mut,
+        seeds = [b"treasury-mint", treasury_mint.mint.as_ref()],
+        bump = treasury_mint.bump,
+    )]
+    pub treasury_mint: Option<Account<'info, TreasuryMint>>,
+    
+    #[account(mut)]
+    pub token_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+// `mint` of None withdraws SOL, otherwise the given SPL mint
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64, mint: Option<Pubkey>) -> Result<()> {
+    match mint {
+        None => withdraw_sol(ctx, amount),
+        Some(mint) => withdraw_token(ctx, amount, mint),
+    }
+}
+
+fn withdraw_sol(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
     let treasury = &mut ctx.accounts.treasury;
     let treasury_vault = &ctx.accounts.treasury_vault;
     let recipient = &ctx.accounts.recipient;
     
+    // Large withdrawals have to be queued through the timelock
+    if amount >= ctx.accounts.timelock.large_withdrawal_threshold {
+        return Err(error!(ErrorCode::RequiresTimelock));
+    }
+    
     // Get treasury vault balance
     let vault_balance = ctx.accounts.treasury_vault.lamports();
     
-    // Calculate maximum withdrawable amount based on reserve percentage
-    let reserved_amount = (vault_balance * treasury.reserve_percentage as u64) / 100;
-    let max_withdrawable = vault_balance.saturating_sub(reserved_amount);
-    
-    // Validate withdrawal amount
-    if amount > max_withdrawable {
-        return Err(error!(ErrorCode::ExceedsWithdrawalLimit));
-    }
+    // Validate against the reserve and rate limit, and update the ledger
+    treasury.record_withdrawal(Clock::get()?.unix_timestamp, vault_balance, amount)?;
     
     // Transfer SOL from treasury vault to recipient
     let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
@@ -68,5 +108,83 @@ pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<
         signer,
     )?;
     
+    Ok(())
+}
+
+fn withdraw_token(ctx: Context<WithdrawTreasury>, amount: u64, mint: Pubkey) -> Result<()> {
+    let accounts = ctx.accounts;
+    
+    // Large withdrawals have to be queued through the timelock
+    let threshold = accounts.treasury_mint
+        .as_ref()
+        .map(|treasury_mint| treasury_mint.large_withdrawal_threshold)
+        .ok_or(error!(ErrorCode::TokenAccountsRequired))?;
+    if amount >= threshold {
+        return Err(error!(ErrorCode::RequiresTimelock));
+    }
+    
+    withdraw_from_token_vault(
+        &accounts.treasury,
+        accounts.treasury_mint.as_mut(),
+        accounts.token_vault.as_ref(),
+        accounts.recipient_token_account.as_ref(),
+        accounts.token_program.as_ref(),
+        mint,
+        amount,
+    )
+}
+
+// Helper function to pay out of a treasury token vault, signed by the treasury PDA.
+// Callers check the large withdrawal threshold and the recipient first.
+pub(crate) fn withdraw_from_token_vault<'info>(
+    treasury: &Account<'info, Treasury>,
+    treasury_mint: Option<&mut Account<'info, TreasuryMint>>,
+    token_vault: Option<&Account<'info, TokenAccount>>,
+    recipient_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+    mint: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let (treasury_mint, token_vault, recipient_token_account, token_program) = match (
+        treasury_mint,
+        token_vault,
+        recipient_token_account,
+        token_program,
+    ) {
+        (Some(treasury_mint), Some(token_vault), Some(recipient_token_account), Some(token_program)) => {
+            (treasury_mint, token_vault, recipient_token_account, token_program)
+        }
+        _ => return Err(error!(ErrorCode::TokenAccountsRequired)),
+    };
+    
+    // Accounts must all belong to the requested mint
+    if treasury_mint.mint != mint
+        || token_vault.key() != treasury_mint.vault
+        || recipient_token_account.mint != mint
+    {
+        return Err(error!(ErrorCode::InvalidTreasuryMint));
+    }
+    
+    // Validate against the mint's reserve and rate limit, and update its ledger
+    treasury_mint.record_withdrawal(Clock::get()?.unix_timestamp, token_vault.amount, amount)?;
+    
+    // Transfer tokens from the treasury token vault, signed by the treasury PDA
+    let treasury_bump = treasury.bump;
+    let seeds = &[b"treasury".as_ref(), &[treasury_bump]];
+    let signer = &[&seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: token_vault.to_account_info(),
+                to: recipient_token_account.to_account_info(),
+                authority: treasury.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+    
     Ok(())
 }
\ No newline at end of file
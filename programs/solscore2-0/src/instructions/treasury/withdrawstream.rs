@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::budget::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"budget", budget.name.as_bytes()],
+        bump = budget.bump,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"stream", budget.key().as_ref(), &stream.id.to_le_bytes()],
+        bump = stream.bump,
+        constraint = stream.recipient == recipient.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub stream: Account<'info, GrantStream>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury-vault"],
+        bump,
+    )]
+    /// CHECK: This is the PDA that holds treasury funds
+    pub treasury_vault: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"protocol-state"],
+        bump = protocol_state.bump,
+        constraint = !protocol_state.paused.treasury @ ErrorCode::ProtocolPaused,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+    let budget = &mut ctx.accounts.budget;
+    let stream = &mut ctx.accounts.stream;
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_vault = &ctx.accounts.treasury_vault;
+    let recipient = &ctx.accounts.recipient;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let amount = stream.vested_amount(current_time) - stream.withdrawn_amount;
+    
+    if amount == 0 {
+        return Err(error!(ErrorCode::NothingVested));
+    }
+    
+    // Paid out of the budget's reservation, still subject to the reserve and rate limit
+    treasury.record_budget_withdrawal(current_time, treasury_vault.lamports(), amount)?;
+    
+    stream.withdrawn_amount += amount;
+    budget.spent += amount;
+    
+    // Transfer SOL from treasury vault to recipient
+    let treasury_vault_bump = *ctx.bumps.get("treasury_vault").unwrap();
+    let seeds = &[b"treasury-vault".as_ref(), &[treasury_vault_bump]];
+    let signer = &[&seeds[..]];
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &treasury_vault.key(),
+            &recipient.key(),
+            amount,
+        ),
+        &[
+            treasury_vault.to_account_info(),
+            recipient.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        signer,
+    )?;
+    
+    Ok(())
+}
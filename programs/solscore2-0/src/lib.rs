@@ -3,6 +3,10 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
+pub mod swap;
+pub mod math;
+pub mod ed25519;
 
 use instructions::*;
 
@@ -18,14 +22,30 @@ pub mod solscore {
         instructions::fpl::initialize_fpl_global(ctx, params)
     }
 
-    pub fn userreg(ctx: Context<RegisterFplUser>, fpl_id: String) -> Result<()> {
-        instructions::fpl::register_fpl_user(ctx, fpl_id)
+    pub fn userreg(ctx: Context<RegisterFplUser>, fpl_id: String, referrer: Option<Pubkey>) -> Result<()> {
+        instructions::fpl::register_fpl_user(ctx, fpl_id, referrer)
     }
 
      pub fn update_fpl_data(ctx: Context<UpdateFplData>) -> Result<()> {
         instructions::fpl::update_fpl_data(ctx)
      }
 
+    pub fn advance_gameweek(ctx: Context<AdvanceGameweek>, gameweek: u8, deadline: i64) -> Result<()> {
+        instructions::fpl::advance_gameweek(ctx, gameweek, deadline)
+    }
+
+    pub fn finalize_gameweek(ctx: Context<FinalizeGameweek>, gameweek: u8) -> Result<()> {
+        instructions::fpl::finalize_gameweek(ctx, gameweek)
+    }
+
+    pub fn update_streak(ctx: Context<UpdateStreak>) -> Result<()> {
+        instructions::fpl::update_streak(ctx)
+    }
+
+    pub fn record_gameweek_score(ctx: Context<RecordGameweekScore>, gameweek: u8, score: u32) -> Result<()> {
+        instructions::fpl::record_gameweek_score(ctx, gameweek, score)
+    }
+
     
     // Stake Instructions
 
@@ -67,8 +87,43 @@ pub mod solscore {
         instructions::reward::initialize_reward_pool(ctx)
     }
     
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
-        instructions::reward::claim_rewards(ctx)
+    pub fn claim_rewards(ctx: Context<ClaimRewards>, stake_id: u64) -> Result<()> {
+        instructions::reward::claim_rewards(ctx, stake_id)
+    }
+    
+    pub fn claim_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAllRewards<'info>>,
+        stake_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::reward::claim_all(ctx, stake_ids)
+    }
+    
+    pub fn set_auto_compound(ctx: Context<SetAutoCompound>, stake_id: u64, enabled: bool) -> Result<()> {
+        instructions::reward::set_auto_compound(ctx, stake_id, enabled)
+    }
+    
+    pub fn compound(ctx: Context<CompoundRewards>, stake_id: u64) -> Result<()> {
+        instructions::reward::compound(ctx, stake_id)
+    }
+    
+    pub fn initialize_vesting_schedule(ctx: Context<InitializeVestingSchedule>) -> Result<()> {
+        instructions::reward::initialize_vesting_schedule(ctx)
+    }
+    
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        instructions::reward::withdraw_vested(ctx)
+    }
+    
+    pub fn exit_vesting(ctx: Context<ExitVesting>) -> Result<()> {
+        instructions::reward::exit_vesting(ctx)
+    }
+    
+    pub fn initialize_referral_stats(ctx: Context<InitializeReferralStats>) -> Result<()> {
+        instructions::reward::initialize_referral_stats(ctx)
+    }
+    
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        instructions::reward::claim_referral_rewards(ctx)
     }
     
     pub fn distribute_rewards(ctx: Context<DistributeRewards>, amount: u64) -> Result<()> {
@@ -80,20 +135,228 @@ pub mod solscore {
         instructions::treasury::treasuryinit(ctx, params)
     }
     
-    pub fn treasurydeposit(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
-        instructions::treasury::treasurydeposit(ctx, amount)
+    pub fn treasurydeposit(ctx: Context<DepositToTreasury>, amount: u64, mint: Option<Pubkey>) -> Result<()> {
+        instructions::treasury::treasurydeposit(ctx, amount, mint)
+    }
+    
+    pub fn withdraw(ctx: Context<WithdrawTreasury>, amount: u64, mint: Option<Pubkey>) -> Result<()> {
+        instructions::treasury::withdraw(ctx, amount, mint)
+    }
+    
+    pub fn add_treasury_mint(ctx: Context<AddTreasuryMint>, params: TreasuryMintParams) -> Result<()> {
+        instructions::treasury::add_treasury_mint(ctx, params)
+    }
+    
+    pub fn initialize_buyback(ctx: Context<InitializeBuyback>, params: BuybackParams) -> Result<()> {
+        instructions::treasury::initialize_buyback(ctx, params)
+    }
+    
+    pub fn execute_buyback<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteBuyback<'info>>,
+        amount_in: u64,
+        selector: [u8; 8],
+    ) -> Result<()> {
+        instructions::treasury::execute_buyback(ctx, amount_in, selector)
+    }
+    
+    // Treasury config changes are queued through the timelock, see `queue_action`
+    
+    pub fn sweep_to_rewards(ctx: Context<SweepToRewards>) -> Result<()> {
+        instructions::treasury::sweep_to_rewards(ctx)
+    }
+    
+    pub fn audit_treasury(ctx: Context<AuditTreasury>) -> Result<TreasuryAudit> {
+        instructions::treasury::audit_treasury(ctx)
+    }
+    
+    pub fn initialize_multisig(ctx: Context<InitializeMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::treasury::initialize_multisig(ctx, signers, threshold)
+    }
+    
+    pub fn create_proposal(ctx: Context<CreateProposal>, kind: ProposalKind, expires_in: i64) -> Result<()> {
+        instructions::treasury::create_proposal(ctx, kind, expires_in)
+    }
+    
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::treasury::approve_proposal(ctx)
+    }
+    
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::treasury::execute_proposal(ctx)
+    }
+    
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::treasury::cancel_proposal(ctx)
+    }
+    
+    pub fn create_budget(ctx: Context<CreateBudget>, name: String, allocation: u64) -> Result<()> {
+        instructions::treasury::create_budget(ctx, name, allocation)
+    }
+    
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        recipient: Pubkey,
+        amount: u64,
+        start_time: i64,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::treasury::create_stream(ctx, recipient, amount, start_time, duration)
+    }
+    
+    pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+        instructions::treasury::withdraw_stream(ctx)
+    }
+    
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        instructions::treasury::cancel_stream(ctx)
+    }
+    
+    // Timelock Instructions
+    pub fn initialize_timelock(ctx: Context<InitializeTimelock>, delay: i64, large_withdrawal_threshold: u64) -> Result<()> {
+        instructions::timelock::initialize_timelock(ctx, delay, large_withdrawal_threshold)
+    }
+    
+    pub fn queue_action(ctx: Context<QueueAction>, action: QueuedAction) -> Result<()> {
+        instructions::timelock::queue_action(ctx, action)
+    }
+    
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        instructions::timelock::cancel_action(ctx)
+    }
+    
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        instructions::timelock::execute_action(ctx)
+    }
+    
+    // Protocol Instructions
+    pub fn initialize_protocol_state(ctx: Context<InitializeProtocolState>, guardian: Pubkey) -> Result<()> {
+        instructions::protocol::initialize_protocol_state(ctx, guardian)
+    }
+    
+    // Unpausing and guardian changes are queued through the timelock
+    pub fn pause(ctx: Context<Pause>, flags: PauseFlags) -> Result<()> {
+        instructions::protocol::pause(ctx, flags)
+    }
+    
+    // Market Instructions
+    pub fn initialize_market_config(ctx: Context<InitializeMarketConfig>, params: MarketConfigParams) -> Result<()> {
+        instructions::market::initialize_market_config(ctx, params)
+    }
+    
+    pub fn create_market(ctx: Context<CreateMarket>, params: MarketParams) -> Result<()> {
+        instructions::market::create_market(ctx, params)
+    }
+    
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        instructions::market::open_position(ctx)
+    }
+    
+    pub fn place_position(ctx: Context<PlacePosition>, outcome: u8, amount: u64) -> Result<()> {
+        instructions::market::place_position(ctx, outcome, amount)
+    }
+    
+    pub fn propose_resolution(ctx: Context<ProposeResolution>, outcome: u8, bond: u64) -> Result<()> {
+        instructions::market::propose_resolution(ctx, outcome, bond)
+    }
+    
+    pub fn dispute(ctx: Context<DisputeResolution>, outcome: u8, bond: u64) -> Result<()> {
+        instructions::market::dispute(ctx, outcome, bond)
+    }
+    
+    pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+        instructions::market::finalize_resolution(ctx)
+    }
+    
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, outcome: u8) -> Result<()> {
+        instructions::market::resolve_dispute(ctx, outcome)
+    }
+    
+    pub fn void_market(ctx: Context<VoidMarket>, delist: bool) -> Result<()> {
+        instructions::market::void_market(ctx, delist)
+    }
+    
+    pub fn create_user_market(ctx: Context<CreateUserMarket>, params: MarketParams, creator_fee_bps: u16) -> Result<()> {
+        instructions::market::create_user_market(ctx, params, creator_fee_bps)
+    }
+    
+    pub fn claim_creator_fees(ctx: Context<CreatorClaim>) -> Result<()> {
+        instructions::market::claim_creator_fees(ctx)
     }
     
-    pub fn withdraw(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
-        instructions::treasury::withdraw(ctx, amount)
+    pub fn reclaim_creation_bond(ctx: Context<CreatorClaim>) -> Result<()> {
+        instructions::market::reclaim_creation_bond(ctx)
     }
     
-    pub fn updatetreasuryconfig(
-        ctx: Context<UpdateTreasuryConfig>, 
-        new_protocol_fee: Option<u8>, 
-        new_reserve_percentage: Option<u8>
+    pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+        instructions::market::claim_winnings(ctx)
+    }
+    
+    pub fn fund_market(ctx: Context<FundMarket>, liquidity: u64) -> Result<()> {
+        instructions::market::fund_market(ctx, liquidity)
+    }
+    
+    pub fn buy_shares(ctx: Context<BuyShares>, outcome: u8, amount: u64, max_cost: u64) -> Result<()> {
+        instructions::market::buy_shares(ctx, outcome, amount, max_cost)
+    }
+    
+    pub fn sell_shares(ctx: Context<SellShares>, outcome: u8, amount: u64, min_refund: u64) -> Result<()> {
+        instructions::market::sell_shares(ctx, outcome, amount, min_refund)
+    }
+    
+    pub fn market_prices(ctx: Context<MarketPrices>) -> Result<Vec<u64>> {
+        instructions::market::market_prices(ctx)
+    }
+    
+    pub fn initialize_outcome_mint(ctx: Context<InitializeOutcomeMint>, outcome: u8) -> Result<()> {
+        instructions::market::initialize_outcome_mint(ctx, outcome)
+    }
+    
+    pub fn mint_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompleteSet<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::market::mint_complete_set(ctx, amount)
+    }
+    
+    pub fn redeem_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompleteSet<'info>>,
+        amount: u64,
     ) -> Result<()> {
-        instructions::treasury::updatetreasuryconfig(ctx, new_protocol_fee, new_reserve_percentage)
+        instructions::market::redeem_complete_set(ctx, amount)
+    }
+    
+    pub fn redeem_winning(ctx: Context<RedeemWinning>, amount: u64) -> Result<()> {
+        instructions::market::redeem_winning(ctx, amount)
+    }
+    
+    pub fn resolve_player_market(ctx: Context<ResolvePlayerMarket>) -> Result<()> {
+        instructions::market::resolve_player_market(ctx)
+    }
+    
+    // Oracle Instructions
+    pub fn initialize_oracle(ctx: Context<InitializeOracle>, publisher: Pubkey) -> Result<()> {
+        instructions::oracle::initialize_oracle(ctx, publisher)
+    }
+    
+    pub fn publish_player_points(
+        ctx: Context<PublishPlayerPoints>,
+        player_id: u32,
+        gameweek: u8,
+        points: i16,
+    ) -> Result<()> {
+        instructions::oracle::publish_player_points(ctx, player_id, gameweek, points)
+    }
+    
+    pub fn correct_player_points(ctx: Context<CorrectPlayerPoints>, points: i16) -> Result<()> {
+        instructions::oracle::correct_player_points(ctx, points)
+    }
+    
+    pub fn create_fixture(ctx: Context<CreateFixture>, payload: FixturePayload) -> Result<()> {
+        instructions::oracle::create_fixture(ctx, payload)
+    }
+    
+    pub fn update_fixture(ctx: Context<UpdateFixture>, payload: FixturePayload) -> Result<()> {
+        instructions::oracle::update_fixture(ctx, payload)
     }
 
     
@@ -0,0 +1,107 @@
+// Unsigned fixed-point helpers, values are scaled by `SCALE`
+
+pub const SCALE: u128 = 1_000_000_000_000;
+pub const LN_2: u128 = 693_147_180_560; // ln(2) * SCALE
+
+// e^-x beyond this rounds to zero at this precision
+const MAX_EXP_NEG_INPUT: u128 = 40 * SCALE;
+
+const EXP_TERMS: u128 = 16;
+const LN_TERMS: u128 = 12;
+
+// e^-x for x >= 0
+pub fn exp_neg(x: u128) -> u128 {
+    if x >= MAX_EXP_NEG_INPUT {
+        return 0;
+    }
+    
+    // e^-x = 2^-k * e^-r with r in [0, ln 2)
+    let k = x / LN_2;
+    let r = x - k * LN_2;
+    
+    // Alternating Taylor series, the partial sums stay positive since r < 1
+    let mut term = SCALE;
+    let mut sum = SCALE;
+    for n in 1..=EXP_TERMS {
+        term = term * r / (n * SCALE);
+        if n % 2 == 1 {
+            sum -= term;
+        } else {
+            sum += term;
+        }
+    }
+    
+    sum >> k
+}
+
+// ln(y) for y >= 1
+pub fn ln(y: u128) -> u128 {
+    debug_assert!(y >= SCALE);
+    
+    // ln(y) = k * ln 2 + ln(m) with m in [1, 2)
+    let mut k = 0;
+    let mut m = y;
+    while m >= 2 * SCALE {
+        m /= 2;
+        k += 1;
+    }
+    
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1) in [0, 1/3)
+    let z = (m - SCALE) * SCALE / (m + SCALE);
+    let z2 = z * z / SCALE;
+    let mut term = z;
+    let mut sum = 0;
+    for i in 0..LN_TERMS {
+        sum += term / (2 * i + 1);
+        term = term * z2 / SCALE;
+    }
+    
+    k * LN_2 + 2 * sum
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn to_fixed(x: f64) -> u128 {
+        (x * SCALE as f64).round() as u128
+    }
+    
+    fn assert_close(actual: u128, expected: f64, tolerance: u128) {
+        let expected = to_fixed(expected);
+        let error = actual.max(expected) - actual.min(expected);
+        assert!(error <= tolerance, "got {}, expected {}", actual, expected);
+    }
+    
+    #[test]
+    fn exp_neg_matches_f64() {
+        for x in [0.0, 1e-9, 0.25, 0.5, 0.693, 1.0, 2.5, 7.0, 13.37, 25.0, 39.9] {
+            assert_close(exp_neg(to_fixed(x)), (-x).exp(), 10);
+        }
+    }
+    
+    #[test]
+    fn exp_neg_rounds_to_zero_past_the_cutoff() {
+        assert_eq!(exp_neg(MAX_EXP_NEG_INPUT), 0);
+        assert_eq!(exp_neg(u128::MAX / SCALE), 0);
+        assert_eq!(exp_neg(0), SCALE);
+    }
+    
+    #[test]
+    fn ln_matches_f64() {
+        for y in [1.0, 1.000001, 1.5, 1.999999, 2.0, 3.0, 10.0, 1e3, 1e6, 1e12] {
+            assert_close(ln(to_fixed(y)), y.ln(), 100);
+        }
+    }
+    
+    #[test]
+    fn ln_inverts_exp_neg() {
+        // e^-x loses relative precision as it shrinks, so stay where it's well resolved
+        for x in [0.1, 1.0, 5.0, 10.0] {
+            let e = exp_neg(to_fixed(x));
+            // ln(1 / e^-x) = x
+            assert_close(ln(SCALE * SCALE / e), x, 100_000);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+// Logarithmic market scoring rule, C(q) = b * ln(sum(e^(q_i / b)))
+//
+// Shares and `b` are in lamports, one winning share pays out one lamport.
+// Costs are evaluated in fixed point with the largest q_i factored out so
+// every exponent is <= 0 and nothing can overflow.
+
+use super::fixed::*;
+
+// Series error is ~1e-11 relative to `b`, round by this much in the market's favour
+const ROUNDING_MARGIN_DIVISOR: u128 = 10_000_000_000;
+
+fn rounding_margin(b: u64) -> u128 {
+    b as u128 / ROUNDING_MARGIN_DIVISOR + 1
+}
+
+// Cost function scaled by `SCALE`
+pub fn lmsr_cost(shares: &[u64], b: u64) -> u128 {
+    let max_shares = *shares.iter().max().unwrap_or(&0);
+    let b = b as u128;
+    
+    let sum: u128 = shares
+        .iter()
+        .map(|q| exp_neg((max_shares - q) as u128 * SCALE / b))
+        .sum();
+    
+    max_shares as u128 * SCALE + b * ln(sum)
+}
+
+// Instantaneous price of each outcome scaled by `SCALE`, these sum to ~1
+pub fn lmsr_prices(shares: &[u64], b: u64) -> Vec<u64> {
+    let max_shares = *shares.iter().max().unwrap_or(&0);
+    let b = b as u128;
+    
+    let weights: Vec<u128> = shares
+        .iter()
+        .map(|q| exp_neg((max_shares - q) as u128 * SCALE / b))
+        .collect();
+    let sum: u128 = weights.iter().sum();
+    
+    weights.iter().map(|w| (w * SCALE / sum) as u64).collect()
+}
+
+// Lamports to buy `amount` shares of `outcome`, rounded up in the market's favour
+pub fn lmsr_buy_cost(shares: &[u64], b: u64, outcome: usize, amount: u64) -> Option<u64> {
+    let mut after = shares.to_vec();
+    after[outcome] = after[outcome].checked_add(amount)?;
+    
+    let delta = lmsr_cost(&after, b).checked_sub(lmsr_cost(shares, b))?;
+    
+    u64::try_from((delta + SCALE - 1) / SCALE + rounding_margin(b)).ok()
+}
+
+// Lamports returned for selling `amount` shares of `outcome`, rounded down
+pub fn lmsr_sell_refund(shares: &[u64], b: u64, outcome: usize, amount: u64) -> Option<u64> {
+    let mut after = shares.to_vec();
+    after[outcome] = after[outcome].checked_sub(amount)?;
+    
+    let delta = lmsr_cost(shares, b).saturating_sub(lmsr_cost(&after, b));
+    
+    u64::try_from((delta / SCALE).saturating_sub(rounding_margin(b))).ok()
+}
+
+// Worst case loss of the market maker, b * ln(n), rounded up
+pub fn lmsr_max_loss(b: u64, outcome_count: usize) -> u64 {
+    let loss = b as u128 * ln(outcome_count as u128 * SCALE);
+    ((loss + SCALE - 1) / SCALE + rounding_margin(b)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const B: u64 = 1_000_000_000;
+    
+    #[test]
+    fn cost_is_at_least_the_largest_position() {
+        let books: [&[u64]; 5] = [
+            &[0, 0],
+            &[5 * B, 0],
+            &[B, 2 * B, 3 * B],
+            &[100 * B, 100 * B],
+            &[u32::MAX as u64, 1, 0, 7],
+        ];
+        for shares in books {
+            let max_shares = *shares.iter().max().unwrap() as u128;
+            assert!(lmsr_cost(shares, B) >= max_shares * SCALE);
+            // and never more than max q_i + b * ln(n)
+            let bound = max_shares * SCALE + B as u128 * ln(shares.len() as u128 * SCALE);
+            assert!(lmsr_cost(shares, B) <= bound);
+        }
+    }
+    
+    #[test]
+    fn buying_then_selling_never_profits() {
+        let mut shares = vec![3 * B, B];
+        for amount in [1, 1_000, B / 3, 10 * B] {
+            for outcome in 0..2 {
+                let cost = lmsr_buy_cost(&shares, B, outcome, amount).unwrap();
+                shares[outcome] += amount;
+                let refund = lmsr_sell_refund(&shares, B, outcome, amount).unwrap();
+                shares[outcome] -= amount;
+                assert!(refund <= cost);
+            }
+        }
+    }
+    
+    #[test]
+    fn buy_cost_is_bounded_by_the_shares_bought() {
+        let shares = vec![2 * B, 0, B];
+        for outcome in 0..3 {
+            let cost = lmsr_buy_cost(&shares, B, outcome, B).unwrap();
+            assert!(cost > 0 && cost <= B + rounding_margin(B) as u64 + 1);
+        }
+    }
+    
+    #[test]
+    fn buy_cost_overflow_returns_none() {
+        assert_eq!(lmsr_buy_cost(&[u64::MAX, 0], B, 0, 1), None);
+    }
+    
+    #[test]
+    fn prices_sum_to_one() {
+        let prices = lmsr_prices(&[B, 4 * B, 0], B);
+        let sum: u64 = prices.iter().sum();
+        assert!(SCALE as u64 - sum < 10);
+    }
+    
+    #[test]
+    fn max_loss_covers_the_worst_outcome() {
+        let shares = vec![0, 0];
+        let loss = lmsr_max_loss(B, 2);
+        // Buying a huge position on one side costs at least its payout minus the subsidy
+        let amount = 50 * B;
+        let cost = lmsr_buy_cost(&shares, B, 0, amount).unwrap();
+        assert!(cost + loss >= amount);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod fixed;
+pub mod lmsr;
+
+pub use fixed::*;
+pub use lmsr::*;
\ No newline at end of file
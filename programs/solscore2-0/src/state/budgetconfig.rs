@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_BUDGET_NAME_LEN: usize = 32;
+
+#[account]
+pub struct Budget {
+    pub name: String,            // Budget category name
+    pub allocation: u64,         // Total lamports allocated to the category
+    pub committed: u64,          // Amount committed to streams
+    pub spent: u64,              // Amount paid out
+    pub stream_count: u64,       // Number of streams created
+    pub bump: u8,                // PDA bump
+}
+
+#[account]
+pub struct GrantStream {
+    pub budget: Pubkey,          // Budget the stream is paid from
+    pub recipient: Pubkey,       // Stream recipient
+    pub id: u64,                 // Stream index within the budget
+    pub total_amount: u64,       // Total amount streamed
+    pub withdrawn_amount: u64,   // Amount withdrawn so far
+    pub start_time: i64,         // Stream start timestamp
+    pub end_time: i64,           // Stream end timestamp
+    pub cancelled: bool,         // Whether the admin cancelled the stream
+    pub bump: u8,                // PDA bump
+}
+
+impl GrantStream {
+    // Amount of the stream unlocked at `now`
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now >= self.end_time || self.end_time <= self.start_time {
+            return self.total_amount;
+        }
+        if now <= self.start_time {
+            return 0;
+        }
+        
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        ((self.total_amount as u128 * elapsed) / duration) as u64
+    }
+}
\ No newline at end of file
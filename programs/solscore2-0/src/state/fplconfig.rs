@@ -8,13 +8,32 @@ pub struct FplUser {
     pub weekly_score: u32,       // Current weekly score
     pub total_score: u32,        // Total season score
     pub last_updated: i64,       // Timestamp of last update
+    pub active_stakes: u32,      // Number of active stake positions
+    pub streak: u16,             // Consecutive finalized gameweeks entered while staked
+    pub last_streak_gameweek: u8, // Last gameweek credited to the streak
+    pub last_entry_gameweek: u8, // Last gameweek with a recorded score
+    pub staked_since_gameweek: u8, // First gameweek of the current unbroken staking run
+    pub referrer: Option<Pubkey>, // Referrer wallet, set once at registration
+    pub registered_at: i64,      // Registration timestamp
     pub bump: u8,                // PDA bump
 }
 
+impl FplUser {
+    // Streak is only valid if it reaches the latest finalized gameweek
+    pub fn effective_streak(&self, finalized_gameweek: u8) -> u16 {
+        if self.active_stakes == 0 || self.last_streak_gameweek < finalized_gameweek {
+            return 0;
+        }
+        self.streak
+    }
+}
+
 #[account]
 pub struct FplGlobalState {
     pub admin: Pubkey,           // Admin authority
     pub current_gameweek: u8,    // Current FPL gameweek
+    pub finalized_gameweek: u8,  // Latest gameweek with final scores
+    pub gameweek_deadline: i64,  // Team deadline of the current gameweek
     pub season_start: i64,       // Season start timestamp
     pub season_end: i64,         // Season end timestamp
     pub api_url: String,         // External FPL API URL
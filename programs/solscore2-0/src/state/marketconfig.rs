@@ -0,0 +1,434 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+pub const MAX_QUESTION_LEN: usize = 128;
+pub const MAX_OUTCOME_LABEL_LEN: usize = 32;
+pub const MAX_RESOLUTION_SOURCE_LEN: usize = 64;
+pub const MIN_OUTCOMES: usize = 2;
+pub const MAX_OUTCOMES: usize = 8;
+pub const MAX_CREATOR_FEE_BPS: u16 = 1_000;
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+
+#[account]
+pub struct MarketConfig {
+    pub admin: Pubkey,           // Admin authority that creates markets
+    pub resolver: Pubkey,        // Can void unresolved markets alongside the admin
+    pub council: Vec<Pubkey>,    // Members who rule on disputed resolutions
+    pub council_threshold: u8,   // Matching votes needed to settle a dispute
+    pub market_count: u64,       // Number of markets created
+    pub min_proposal_bond: u64,  // Minimum bond to propose an outcome
+    pub dispute_window: i64,     // Seconds a proposal can be disputed for
+    pub auto_void_delay: i64,    // Anyone can void a market this long after close without a resolution
+    pub creation_bond: u64,      // Bond posted by users creating a market
+    pub min_creator_stake: u64,  // Minimum single stake to create a market
+    pub max_creator_fee_bps: u16, // Cap on the fee a market creator can set
+    pub bump: u8,                // PDA bump
+}
+
+impl MarketConfig {
+    // Council must be non-empty, unique, and able to reach the threshold
+    pub fn validate_council(council: &[Pubkey], threshold: u8) -> Result<()> {
+        if council.is_empty() || council.len() > MAX_COUNCIL_MEMBERS {
+            return Err(error!(ErrorCode::InvalidMarketParameter));
+        }
+        
+        for (i, member) in council.iter().enumerate() {
+            if council[..i].contains(member) {
+                return Err(error!(ErrorCode::InvalidMarketParameter));
+            }
+        }
+        
+        if threshold == 0 || threshold as usize > council.len() {
+            return Err(error!(ErrorCode::InvalidMarketParameter));
+        }
+        
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MarketStatus {
+    Open,
+    Resolved,
+    Voided,                      // Cancelled, every position is refunded at cost
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MarketMechanism {
+    Parimutuel,                  // Pooled stakes, odds known at close
+    Lmsr,                        // Treasury-funded market maker with live prices
+    OutcomeTokens,               // Complete sets of per-outcome SPL tokens
+}
+
+// Per-wallet caps, enforced against the wallet's position account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PositionLimits {
+    pub max_position: u64,       // Max collateral per wallet across outcomes (0 = no cap)
+    pub max_pool_share_bps: u16, // Max share of any single outcome pool (0 = no cap)
+    pub share_cap_min_pool: u64, // The pool share cap applies once the outcome pool is this large
+    pub min_registration_age: i64, // Min FPL registration age in seconds (0 = not required)
+}
+
+impl PositionLimits {
+    pub const SIZE: usize = 8 + 2 + 8 + 8;
+    
+    // Check a wallet's totals after the new position is added
+    pub fn check(&self, position_total: u64, outcome_holding: u64, outcome_pool: u64) -> Result<()> {
+        if self.max_position > 0 && position_total > self.max_position {
+            return Err(error!(ErrorCode::PositionLimitExceeded));
+        }
+        
+        if self.max_pool_share_bps > 0
+            && outcome_pool >= self.share_cap_min_pool
+            && outcome_holding as u128 * 10_000 > outcome_pool as u128 * self.max_pool_share_bps as u128
+        {
+            return Err(error!(ErrorCode::PoolShareExceeded));
+        }
+        
+        Ok(())
+    }
+    
+    // Wallets must have been registered for `min_registration_age` seconds
+    pub fn check_registration(&self, registered_at: Option<i64>, now: i64) -> Result<()> {
+        if self.min_registration_age == 0 {
+            return Ok(());
+        }
+        
+        match registered_at {
+            Some(registered_at) if now - registered_at >= self.min_registration_age => Ok(()),
+            _ => Err(error!(ErrorCode::RegistrationTooRecent)),
+        }
+    }
+}
+
+// Player markets are binary, outcome 0 is "yes" and outcome 1 is "no"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerCondition {
+    PointsAtLeast { player_id: u32, gameweek: u8, threshold: i16 },
+    Outscores { player_id: u32, other_player_id: u32, gameweek: u8 },
+}
+
+impl PlayerCondition {
+    pub const SIZE: usize = 1 + 4 + 4 + 1;
+    
+    pub fn gameweek(&self) -> u8 {
+        match self {
+            PlayerCondition::PointsAtLeast { gameweek, .. } => *gameweek,
+            PlayerCondition::Outscores { gameweek, .. } => *gameweek,
+        }
+    }
+    
+    // Winning outcome given the published points, `other_points` is only used by `Outscores`
+    pub fn evaluate(&self, points: i16, other_points: i16) -> u8 {
+        let yes = match self {
+            PlayerCondition::PointsAtLeast { threshold, .. } => points >= *threshold,
+            PlayerCondition::Outscores { .. } => points > other_points,
+        };
+        if yes { 0 } else { 1 }
+    }
+}
+
+#[account]
+pub struct Market {
+    pub id: u64,                 // Market index
+    pub creator: Pubkey,         // Market creator, the admin for protocol markets
+    pub creator_fee_bps: u16,    // Creator's cut of the losing pools or LMSR trades
+    pub creator_fees_owed: u64,  // Creator fees held in the vault, not yet claimed
+    pub creation_bond: u64,      // Creation bond held in the bond vault
+    pub delisted: bool,          // Voided by moderation, the creation bond is slashed
+    pub question: String,        // Question being predicted
+    pub fixture_id: u64,         // FPL fixture the market is about
+    pub outcomes: Vec<String>,   // Outcome labels
+    pub mechanism: MarketMechanism, // How positions are priced and settled
+    pub liquidity: u64,          // LMSR liquidity parameter `b` (0 = not funded)
+    pub subsidy: u64,            // LMSR max loss funded by the treasury
+    pub shares: Vec<u64>,        // LMSR shares outstanding per outcome
+    pub cost_basis: u64,         // LMSR cost of open positions, refunded if voided
+    pub outcome_mints: Vec<Pubkey>, // Share token mint per outcome, in outcome order
+    pub outcome_pools: Vec<u64>, // Collateral placed on each outcome
+    pub total_pool: u64,         // Total collateral in the market (incl. LMSR subsidy, excl. vault rent)
+    pub fee_amount: u64,         // Protocol fee taken from the losing pools
+    pub distributable: u64,      // Losing pools net of the fee, shared by winners
+    pub claimed_stake: u64,      // Winning stake that has been paid out
+    pub distributed: u64,        // Share of `distributable` paid out so far
+    pub close_time: i64,         // No positions after this timestamp
+    pub resolution_source: String, // Where the outcome is taken from
+    pub player_condition: Option<PlayerCondition>, // Set for oracle-resolved FPL player markets
+    pub limits: PositionLimits,  // Per-wallet position caps
+    pub status: MarketStatus,    // Market lifecycle status
+    pub winning_outcome: Option<u8>, // Set on resolution
+    pub resolved_at: i64,        // Resolution timestamp
+    pub bump: u8,                // PDA bump
+}
+
+impl Market {
+    pub const SPACE: usize = 8 + 8 + 32 + 2 + 8 + 8 + 1 + (4 + MAX_QUESTION_LEN) + 8
+        + (4 + MAX_OUTCOMES * (4 + MAX_OUTCOME_LABEL_LEN)) + 1 + 8 + 8 + (4 + MAX_OUTCOMES * 8) + 8
+        + (4 + MAX_OUTCOMES * 32) + (4 + MAX_OUTCOMES * 8) + 8 + 8 + 8 + 8 + 8 + 8
+        + (4 + MAX_RESOLUTION_SOURCE_LEN) + (1 + PlayerCondition::SIZE) + PositionLimits::SIZE
+        + 1 + (1 + 1) + 8 + 1;
+    
+    pub fn creator_fee(&self, amount: u64) -> u64 {
+        (amount * self.creator_fee_bps as u64) / 10_000
+    }
+    
+    // Winners share the losing pools pro-rata, net of the protocol and creator fees
+    pub fn settle(&mut self, winning_outcome: u8, protocol_fee: u8, now: i64) {
+        let winning_pool = self.outcome_pools[winning_outcome as usize];
+        let losing_pool = self.total_pool - winning_pool;
+        
+        // With no winning stake nothing is charged and everyone is refunded
+        if self.mechanism == MarketMechanism::Parimutuel && winning_pool > 0 {
+            let creator_fee = self.creator_fee(losing_pool);
+            self.fee_amount = (losing_pool * protocol_fee as u64) / 100;
+            self.distributable = losing_pool - self.fee_amount - creator_fee;
+            self.creator_fees_owed += creator_fee;
+        }
+        
+        self.status = MarketStatus::Resolved;
+        self.winning_outcome = Some(winning_outcome);
+        self.resolved_at = now;
+    }
+    
+    pub fn winning_pool(&self) -> u64 {
+        match self.winning_outcome {
+            Some(outcome) => self.outcome_pools[outcome as usize],
+            None => 0,
+        }
+    }
+    
+    // Every outcome needs its share token before sets can be minted
+    pub fn has_outcome_mints(&self) -> bool {
+        self.outcome_mints.len() == self.outcomes.len()
+    }
+    
+    // Resolved with no winning stake at all
+    pub fn is_refund(&self) -> bool {
+        self.mechanism == MarketMechanism::Parimutuel
+            && self.status == MarketStatus::Resolved
+            && self.winning_pool() == 0
+    }
+    
+    // Collateral left once every position is refunded at cost, returned to the treasury.
+    // Profitable sells can leave the open cost basis above the pool, then nothing is left.
+    pub fn void_surplus(&self) -> u64 {
+        match self.mechanism {
+            MarketMechanism::Lmsr => self.total_pool.saturating_sub(self.cost_basis),
+            _ => 0,
+        }
+    }
+    
+    // Refund owed on a voided position. LMSR positions are refunded at cost, scaled
+    // down pro-rata when the pool can't cover the whole cost basis.
+    pub fn void_refund_for(&self, cost: u64) -> u64 {
+        match self.mechanism {
+            MarketMechanism::Lmsr if self.status == MarketStatus::Voided && self.cost_basis > 0 => {
+                ((cost as u128 * self.distributable as u128) / self.cost_basis as u128) as u64
+            }
+            _ => cost,
+        }
+    }
+    
+    // Collateral left once every winning LMSR share is paid, returned to the treasury
+    pub fn lmsr_surplus(&self) -> u64 {
+        match self.winning_outcome {
+            Some(outcome) => self.total_pool - self.shares[outcome as usize],
+            None => 0,
+        }
+    }
+    
+    // Share of the losing pools owed on a winning stake, rounded down
+    pub fn winnings_for(&self, winning_stake: u64) -> u64 {
+        ((winning_stake as u128 * self.distributable as u128) / self.winning_pool() as u128) as u64
+    }
+    
+    // Record a winning claim, returns the rounding remainder once every winner is paid
+    pub fn record_claim(&mut self, winning_stake: u64, winnings: u64) -> u64 {
+        self.claimed_stake += winning_stake;
+        self.distributed += winnings;
+        
+        if self.claimed_stake == self.winning_pool() {
+            return self.distributable - self.distributed;
+        }
+        0
+    }
+}
+
+#[account]
+pub struct Position {
+    pub market: Pubkey,          // Market the position is in
+    pub owner: Pubkey,           // Position owner
+    pub amounts: Vec<u64>,       // Collateral placed on each outcome
+    pub shares: Vec<u64>,        // LMSR shares held per outcome
+    pub claimed: bool,           // Whether winnings have been claimed
+    pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionStatus {
+    Proposed,                    // Open to disputes until the deadline
+    Disputed,                    // Escalated to the dispute council
+    Finalized,                   // Market settled and bonds paid out
+}
+
+#[account]
+pub struct ResolutionProposal {
+    pub market: Pubkey,          // Market being resolved
+    pub proposer: Pubkey,        // Account that proposed the outcome
+    pub proposed_outcome: u8,    // Proposed winning outcome
+    pub proposer_bond: u64,      // Bond posted by the proposer
+    pub disputer: Option<Pubkey>, // Account that disputed the proposal
+    pub disputed_outcome: u8,    // Outcome the disputer claims instead
+    pub disputer_bond: u64,      // Bond posted by the disputer
+    pub dispute_deadline: i64,   // Proposal finalizes after this if undisputed
+    pub status: ResolutionStatus, // Resolution lifecycle status
+    pub votes: Vec<CouncilVote>, // Council votes on a disputed proposal
+    pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CouncilVote {
+    pub member: Pubkey,          // Council member who voted
+    pub outcome: u8,             // Outcome they ruled for
+}
+
+impl ResolutionProposal {
+    // Votes for an outcome, only members of the current council count
+    pub fn tally(&self, council: &[Pubkey], outcome: u8) -> usize {
+        self.votes
+            .iter()
+            .filter(|vote| vote.outcome == outcome && council.contains(&vote.member))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::*;
+    
+    const B: u64 = 1_000_000_000;
+    
+    fn funded_lmsr_market() -> Market {
+        let subsidy = lmsr_max_loss(B, 2);
+        Market {
+            id: 0,
+            creator: Pubkey::default(),
+            creator_fee_bps: 0,
+            creator_fees_owed: 0,
+            creation_bond: 0,
+            delisted: false,
+            question: String::new(),
+            fixture_id: 0,
+            outcomes: vec!["Yes".to_string(), "No".to_string()],
+            mechanism: MarketMechanism::Lmsr,
+            liquidity: B,
+            subsidy,
+            shares: vec![0, 0],
+            cost_basis: 0,
+            outcome_mints: vec![],
+            outcome_pools: vec![0, 0],
+            total_pool: subsidy,
+            fee_amount: 0,
+            distributable: 0,
+            claimed_stake: 0,
+            distributed: 0,
+            close_time: 0,
+            resolution_source: String::new(),
+            player_condition: None,
+            limits: PositionLimits::default(),
+            status: MarketStatus::Open,
+            winning_outcome: None,
+            resolved_at: 0,
+            bump: 0,
+        }
+    }
+    
+    // Mirrors the ledger updates in `buy_shares`, returns the cost paid
+    fn buy(market: &mut Market, outcome: usize, amount: u64) -> u64 {
+        let cost = lmsr_buy_cost(&market.shares, market.liquidity, outcome, amount).unwrap();
+        market.shares[outcome] += amount;
+        market.total_pool += cost;
+        market.cost_basis += cost;
+        cost
+    }
+    
+    // Mirrors the ledger updates in `sell_shares` for a full exit
+    fn sell(market: &mut Market, outcome: usize, amount: u64, cost: u64) -> u64 {
+        let refund = lmsr_sell_refund(&market.shares, market.liquidity, outcome, amount).unwrap();
+        market.shares[outcome] -= amount;
+        market.total_pool -= refund;
+        market.cost_basis -= cost;
+        refund
+    }
+    
+    // Mirrors the ledger updates in `void_market`
+    fn void(market: &mut Market) {
+        market.status = MarketStatus::Voided;
+        market.total_pool -= market.void_surplus();
+        market.distributable = market.total_pool.min(market.cost_basis);
+    }
+    
+    #[test]
+    fn council_tally_counts_current_members_per_outcome() {
+        let council = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(MarketConfig::validate_council(&council, 2).is_ok());
+        assert!(MarketConfig::validate_council(&council, 4).is_err());
+        assert!(MarketConfig::validate_council(&[council[0], council[0]], 1).is_err());
+        
+        let vote = |member: Pubkey, outcome: u8| CouncilVote { member, outcome };
+        let resolution = ResolutionProposal {
+            market: Pubkey::default(),
+            proposer: Pubkey::default(),
+            proposed_outcome: 0,
+            proposer_bond: 0,
+            disputer: None,
+            disputed_outcome: 1,
+            disputer_bond: 0,
+            dispute_deadline: 0,
+            status: ResolutionStatus::Disputed,
+            votes: vec![vote(council[0], 1), vote(council[1], 0), vote(Pubkey::new_unique(), 1)],
+            bump: 0,
+        };
+        
+        assert_eq!(resolution.tally(&council, 1), 1);
+        assert_eq!(resolution.tally(&council, 0), 1);
+        assert_eq!(resolution.tally(&council, 2), 0);
+    }
+    
+    #[test]
+    fn void_refunds_at_cost_and_returns_surplus() {
+        let mut market = funded_lmsr_market();
+        let cost = buy(&mut market, 0, 2 * B);
+        
+        let surplus = market.void_surplus();
+        assert_eq!(surplus, market.subsidy);
+        
+        void(&mut market);
+        assert_eq!(market.void_refund_for(cost), cost);
+        assert_eq!(market.total_pool, cost);
+    }
+    
+    #[test]
+    fn void_after_profitable_round_trip_stays_within_pool() {
+        let mut market = funded_lmsr_market();
+        
+        // C makes outcome 1 cheap, A buys it, D pushes it up and A sells at a profit
+        let c_cost = buy(&mut market, 0, 20 * B);
+        let a_cost = buy(&mut market, 1, 5 * B);
+        let d_cost = buy(&mut market, 1, 25 * B);
+        let a_refund = sell(&mut market, 1, 5 * B, a_cost);
+        assert!(a_refund > a_cost + market.subsidy);
+        
+        // The open cost basis is now more than the pool holds
+        assert!(market.cost_basis > market.total_pool);
+        assert_eq!(market.void_surplus(), 0);
+        
+        void(&mut market);
+        let c_refund = market.void_refund_for(c_cost);
+        let d_refund = market.void_refund_for(d_cost);
+        assert!(c_refund <= c_cost && d_refund <= d_cost);
+        assert!(c_refund + d_refund <= market.total_pool);
+        assert!(market.total_pool - (c_refund + d_refund) <= 2);
+    }
+}
\ No newline at end of file
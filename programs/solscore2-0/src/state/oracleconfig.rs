@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_FIXTURE_HISTORY: usize = 8;
+
+#[account]
+pub struct OracleConfig {
+    pub admin: Pubkey,           // Admin authority that rotates the publisher
+    pub publisher: Pubkey,       // Oracle key that publishes FPL data
+    pub bump: u8,                // PDA bump
+}
+
+#[account]
+pub struct PlayerGameweekPoints {
+    pub player_id: u32,          // FPL element id
+    pub gameweek: u8,            // Gameweek the points were scored in
+    pub points: i16,             // Total points, can be negative
+    pub published_at: i64,       // Timestamp of the last update
+    pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureStatus {
+    Scheduled,
+    Live,
+    Finished,
+    Postponed,
+    Abandoned,
+}
+
+impl FixtureStatus {
+    // Markets on the fixture can't settle normally
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, FixtureStatus::Postponed | FixtureStatus::Abandoned)
+    }
+}
+
+// Payload signed off-chain by the oracle publisher
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FixturePayload {
+    pub fixture_id: u64,
+    pub sequence: u32,           // Must increase with every update, stops replays
+    pub gameweek: u8,
+    pub home_team: u8,
+    pub away_team: u8,
+    pub kickoff: i64,
+    pub status: FixtureStatus,
+    pub home_score: Option<u8>,
+    pub away_score: Option<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FixtureStatusChange {
+    pub status: FixtureStatus,   // Status moved to
+    pub kickoff: i64,            // Kickoff at the time of the change
+    pub changed_at: i64,         // Timestamp of the change
+}
+
+impl FixtureStatusChange {
+    pub const SIZE: usize = 1 + 8 + 8;
+}
+
+#[account]
+pub struct Fixture {
+    pub fixture_id: u64,         // FPL fixture id
+    pub sequence: u32,           // Sequence of the last applied payload
+    pub gameweek: u8,            // Gameweek the fixture is in
+    pub home_team: u8,           // FPL team id
+    pub away_team: u8,           // FPL team id
+    pub kickoff: i64,            // Current kickoff timestamp
+    pub status: FixtureStatus,   // Current status
+    pub home_score: Option<u8>,  // Final home score
+    pub away_score: Option<u8>,  // Final away score
+    pub history: Vec<FixtureStatusChange>, // Latest status and kickoff changes, oldest first
+    pub bump: u8,                // PDA bump
+}
+
+impl Fixture {
+    // Record status or kickoff changes, keeping the most recent entries
+    pub fn apply(&mut self, payload: &FixturePayload, now: i64) {
+        if payload.status != self.status || payload.kickoff != self.kickoff {
+            if self.history.len() == MAX_FIXTURE_HISTORY {
+                self.history.remove(0);
+            }
+            self.history.push(FixtureStatusChange {
+                status: payload.status,
+                kickoff: payload.kickoff,
+                changed_at: now,
+            });
+        }
+        
+        self.sequence = payload.sequence;
+        self.gameweek = payload.gameweek;
+        self.home_team = payload.home_team;
+        self.away_team = payload.away_team;
+        self.kickoff = payload.kickoff;
+        self.status = payload.status;
+        self.home_score = payload.home_score;
+        self.away_score = payload.away_score;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ProtocolState {
+    pub admin: Pubkey,           // Admin authority, the only one who can unpause (via the timelock)
+    pub guardian: Pubkey,        // Guardian key that can pause instantly
+    pub paused: PauseFlags,      // Instruction groups currently paused
+    pub last_paused_at: i64,     // Timestamp of the last pause
+    pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PauseFlags {
+    pub staking: bool,           // New stakes
+    pub unstaking: bool,         // Unstaking
+    pub claims: bool,            // Reward, vesting and referral claims
+    pub markets: bool,           // Prediction markets
+    pub treasury: bool,          // Treasury outflows
+}
+
+impl PauseFlags {
+    pub const SIZE: usize = 1 + 1 + 1 + 1 + 1;
+    
+    // Pausing only ever adds flags
+    pub fn pause(&mut self, flags: &PauseFlags) {
+        self.staking |= flags.staking;
+        self.unstaking |= flags.unstaking;
+        self.claims |= flags.claims;
+        self.markets |= flags.markets;
+        self.treasury |= flags.treasury;
+    }
+    
+    // Unpausing only ever clears flags
+    pub fn unpause(&mut self, flags: &PauseFlags) {
+        self.staking &= !flags.staking;
+        self.unstaking &= !flags.unstaking;
+        self.claims &= !flags.claims;
+        self.markets &= !flags.markets;
+        self.treasury &= !flags.treasury;
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        !(self.staking || self.unstaking || self.claims || self.markets || self.treasury)
+    }
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub const MAX_COMPOUND_TIP_BPS: u16 = 1_000;
+
 #[account]
 pub struct RewardPool {
     pub total_rewards: u64,      // Total reward pool in SOL (lamports)
@@ -14,5 +16,77 @@ pub struct RewardConfig {
     pub base_apy: u8,            // Base APY percentage
     pub score_multiplier: u8,    // Multiplier for FPL scores
     pub distribution_frequency: u64, // Distribution frequency in seconds
+    pub compound_tip_bps: u16,   // Cranker tip on compounded rewards (basis points)
+    pub vesting_enabled: bool,   // Whether claims vest instead of paying out
+    pub vesting_days: u16,       // Linear vesting duration in days
+    pub streak_bonus: u8,        // Bonus percentage per streak gameweek
+    pub max_streak_bonus: u8,    // Cap on the total streak bonus percentage
+    pub referral_share: u8,      // Referrer share of referee claims (percentage)
+    pub bump: u8,                // PDA bump
+}
+
+impl RewardConfig {
+    // Vesting duration in seconds
+    pub fn vesting_duration(&self) -> i64 {
+        self.vesting_days as i64 * 86_400
+    }
+    
+    // Streak bonus percentage, linear per gameweek up to the cap
+    pub fn streak_bonus_for(&self, streak: u16) -> u64 {
+        let bonus = streak as u64 * self.streak_bonus as u64;
+        bonus.min(self.max_streak_bonus as u64)
+    }
+}
+
+#[account]
+pub struct VestingSchedule {
+    pub owner: Pubkey,           // Beneficiary of the vested rewards
+    pub total_amount: u64,       // Amount vesting in the current schedule
+    pub withdrawn_amount: u64,   // Amount withdrawn from the current schedule
+    pub released_amount: u64,    // Vested amount carried over from previous schedules
+    pub start_time: i64,         // Start of the current schedule
+    pub end_time: i64,           // End of the current schedule
+    pub bump: u8,                // PDA bump
+}
+
+impl VestingSchedule {
+    // Amount of the current schedule unlocked at `now`
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now >= self.end_time || self.end_time <= self.start_time {
+            return self.total_amount;
+        }
+        if now <= self.start_time {
+            return 0;
+        }
+        
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        ((self.total_amount as u128 * elapsed) / duration) as u64
+    }
+    
+    // Amount the owner can withdraw at `now`
+    pub fn withdrawable_amount(&self, now: i64) -> u64 {
+        self.released_amount + self.vested_amount(now) - self.withdrawn_amount
+    }
+    
+    // Restart the schedule with the unvested remainder plus `amount`,
+    // keeping anything already vested withdrawable
+    pub fn extend(&mut self, amount: u64, now: i64, duration: i64) {
+        let vested = self.vested_amount(now);
+        
+        self.released_amount += vested - self.withdrawn_amount;
+        self.total_amount = self.total_amount - vested + amount;
+        self.withdrawn_amount = 0;
+        self.start_time = now;
+        self.end_time = now + duration;
+    }
+}
+
+#[account]
+pub struct ReferralStats {
+    pub referrer: Pubkey,        // Referrer wallet
+    pub referee_count: u32,      // Number of users referred
+    pub total_earned: u64,       // Total referral rewards credited
+    pub total_claimed: u64,      // Total referral rewards claimed
     pub bump: u8,                // PDA bump
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ pub struct Stake {
     pub fpl_user: Pubkey,        // Associated FPL user account
     pub is_active: bool,         // Whether stake is active
     pub last_claim_time: i64,    // Last reward claim timestamp
+    pub auto_compound: bool,     // Whether rewards are compounded into the principal
     pub bump: u8,                // PDA bump
 }
 
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::treasury::*;
+use crate::state::protocol::*;
+use crate::errors::*;
+
+pub const MIN_TIMELOCK_DELAY: i64 = 86_400;
+pub const TIMELOCK_GRACE_PERIOD: i64 = 14 * 86_400;
+
+#[account]
+pub struct Timelock {
+    pub admin: Pubkey,           // Admin authority that queues and cancels actions
+    pub delay: i64,              // Minimum delay before a queued action can run
+    pub large_withdrawal_threshold: u64, // Withdrawals at or above this must be queued
+    pub action_count: u64,       // Number of actions queued
+    pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum QueuedAction {
+    UpdateTreasuryConfig { update: TreasuryConfigUpdate },
+    UpdateTreasuryMint { mint: Pubkey, update: TreasuryMintUpdate },
+    UpdateRewardApy { base_apy: u8 },
+    UpdateStakeFee { early_withdrawal_fee: u8 },
+    UpdateTimelock { delay: i64, large_withdrawal_threshold: u64 },
+    Withdraw { amount: u64, recipient: Pubkey, mint: Option<Pubkey> },
+    Unpause { flags: PauseFlags },
+    SetGuardian { guardian: Pubkey },
+    SetSwapPrograms { swap_programs: Vec<Pubkey> },
+    SetBuybackRate { reference_rate: u64 },
+    CreateBudget { name: String, allocation: u64 },
+}
+
+// Helper function to check a queued eta has passed and is still within the grace period
+pub fn check_eta(eta: i64, now: i64) -> Result<()> {
+    if eta == 0 || now < eta {
+        return Err(error!(ErrorCode::TimelockNotReady));
+    }
+    
+    // Stale actions have to be queued again
+    if now > eta + TIMELOCK_GRACE_PERIOD {
+        return Err(error!(ErrorCode::ActionExpired));
+    }
+    
+    Ok(())
+}
+
+#[account]
+pub struct PendingAction {
+    pub id: u64,                 // Action index
+    pub action: QueuedAction,    // Change to apply
+    pub queued_at: i64,          // Timestamp the action was queued
+    pub eta: i64,                // Earliest execution timestamp
+    pub executed: bool,          // Whether the action has been executed
+    pub bump: u8,                // PDA bump
+}
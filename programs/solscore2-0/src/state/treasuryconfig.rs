@@ -1,10 +1,477 @@
 use anchor_lang::prelude::*;
+use crate::errors::*;
+
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_PROPOSAL_MEMO_LEN: usize = 64;
+pub const MAX_PROPOSAL_LIFETIME: i64 = 30 * 86_400;
+pub const MAX_SWAP_VENUES: usize = 4;
+pub const OUTFLOW_BUCKETS: usize = 24;
 
 #[account]
 pub struct Treasury {
     pub admin: Pubkey,           // Admin authority
     pub total_fees: u64,         // Total collected fees
+    pub total_deposits: u64,     // Principal deposited directly, never shared with rewards
     pub protocol_fee: u8,        // Protocol fee percentage (0-100)
     pub reserve_percentage: u8,  // Percentage to keep as reserves
+    pub rewards_share: u8,       // Percentage of fees routed to the reward pool
+    pub pending_rewards: u64,    // Fee share accrued but not yet swept to rewards
+    pub budgeted: u64,           // Fees reserved for budgets and not yet paid out
+    pub multisig_enabled: bool,  // Withdrawals require multisig approval
+    pub outflow_limit: OutflowLimit, // Rolling window cap on SOL outflows
     pub bump: u8,                // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OutflowLimit {
+    pub cap: u64,                // Max amount out per window (0 = no cap)
+    pub cap_percentage: u8,      // Max percentage of the vault out per window (0 = no cap)
+    pub window: i64,             // Rolling window length in seconds
+    pub buckets: [u64; OUTFLOW_BUCKETS], // Outflow per time bucket, a ring indexed by bucket number
+    pub last_bucket: i64,        // Bucket number of the last outflow
+}
+
+impl OutflowLimit {
+    pub const SIZE: usize = 8 + 1 + 8 + 8 * OUTFLOW_BUCKETS + 8;
+    
+    pub fn new(cap: u64, cap_percentage: u8, window: i64) -> Result<Self> {
+        if cap_percentage > 100 || window <= 0 {
+            return Err(error!(ErrorCode::InvalidTreasuryParameter));
+        }
+        
+        Ok(Self {
+            cap,
+            cap_percentage,
+            window,
+            buckets: [0; OUTFLOW_BUCKETS],
+            last_bucket: 0,
+        })
+    }
+    
+    // Buckets are sized so all but the current one span a full window. An outflow
+    // stays counted until its whole bucket is out of the window, never less.
+    fn bucket_len(&self) -> i64 {
+        let spans = OUTFLOW_BUCKETS as i64 - 1;
+        ((self.window + spans - 1) / spans).max(1)
+    }
+    
+    fn slot(bucket: i64) -> usize {
+        bucket.rem_euclid(OUTFLOW_BUCKETS as i64) as usize
+    }
+    
+    // Clear the buckets that have aged out since the last outflow
+    fn advance(&mut self, now: i64) {
+        let bucket = now / self.bucket_len();
+        let elapsed = bucket - self.last_bucket;
+        if elapsed <= 0 {
+            return;
+        }
+        
+        if elapsed >= OUTFLOW_BUCKETS as i64 {
+            self.buckets = [0; OUTFLOW_BUCKETS];
+        } else {
+            for aged in self.last_bucket + 1..=bucket {
+                self.buckets[Self::slot(aged)] = 0;
+            }
+        }
+        self.last_bucket = bucket;
+    }
+    
+    // Outflow within the current window
+    pub fn window_outflow(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+    
+    // Changing the window resizes the buckets, everything already counted
+    // moves into the current bucket so it stays counted for a full window
+    pub fn set_window(&mut self, now: i64, window: i64) {
+        self.advance(now);
+        let recent_outflow = self.window_outflow();
+        
+        self.window = window;
+        self.buckets = [0; OUTFLOW_BUCKETS];
+        self.last_bucket = now / self.bucket_len();
+        self.buckets[Self::slot(self.last_bucket)] = recent_outflow;
+    }
+    
+    // Check an outflow against the rolling window caps and record it
+    pub fn record(&mut self, now: i64, vault_balance: u64, amount: u64) -> Result<()> {
+        self.advance(now);
+        let recent_outflow = self.window_outflow();
+        let window_total = recent_outflow + amount;
+        
+        if self.cap > 0 && window_total > self.cap {
+            return Err(error!(ErrorCode::OutflowLimitExceeded));
+        }
+        
+        // Percentage cap is measured against the balance before the window's outflows
+        if self.cap_percentage > 0 {
+            let window_base = vault_balance + recent_outflow;
+            let max_outflow = (window_base as u128 * self.cap_percentage as u128 / 100) as u64;
+            if window_total > max_outflow {
+                return Err(error!(ErrorCode::OutflowLimitExceeded));
+            }
+        }
+        
+        self.buckets[Self::slot(self.last_bucket)] += amount;
+        
+        Ok(())
+    }
+}
+
+// Helper function to check a withdrawal against the vault reserve and the fee ledger
+fn check_reserve(vault_balance: u64, reserve_percentage: u8, recorded_fees: u64, amount: u64) -> Result<()> {
+    // Calculate maximum withdrawable amount based on reserve percentage
+    let reserved_amount = (vault_balance * reserve_percentage as u64) / 100;
+    let max_withdrawable = vault_balance.saturating_sub(reserved_amount);
+    
+    // Validate withdrawal amount
+    if amount > max_withdrawable || amount > recorded_fees {
+        return Err(error!(ErrorCode::ExceedsWithdrawalLimit));
+    }
+    
+    Ok(())
+}
+
+impl Treasury {
+    // Record a fee and accrue the reward pool's share of it
+    pub fn credit_fee(&mut self, amount: u64) {
+        self.total_fees += amount;
+        self.pending_rewards += (amount * self.rewards_share as u64) / 100;
+    }
+    
+    // Funds coming back from a market subsidy, no reward share is taken
+    pub fn credit_return(&mut self, amount: u64) {
+        self.total_fees += amount;
+    }
+    
+    // Principal deposited into the treasury, kept on the ledger without a reward share
+    pub fn credit_deposit(&mut self, amount: u64) {
+        self.total_fees += amount;
+        self.total_deposits += amount;
+    }
+    
+    // Split a gross payout into the protocol fee and the net amount
+    pub fn split_protocol_fee(&self, gross: u64) -> (u64, u64) {
+        let fee = (gross * self.protocol_fee as u64) / 100;
+        (fee, gross - fee)
+    }
+    
+    // Check an outflow against the rolling window caps and record it
+    pub fn record_outflow(&mut self, now: i64, vault_balance: u64, amount: u64) -> Result<()> {
+        self.outflow_limit.record(now, vault_balance, amount)
+    }
+    
+    // Fees not already owed to the reward pool or reserved for a budget
+    pub fn withdrawable_fees(&self) -> u64 {
+        self.total_fees.saturating_sub(self.pending_rewards + self.budgeted)
+    }
+    
+    // Set fees aside for a budget so they can't be withdrawn or allocated twice
+    pub fn reserve_budget(&mut self, allocation: u64) -> Result<()> {
+        if allocation > self.withdrawable_fees() {
+            return Err(error!(ErrorCode::ExceedsWithdrawalLimit));
+        }
+        
+        self.budgeted += allocation;
+        
+        Ok(())
+    }
+    
+    // Validate a withdrawal against the reserve and take it off the ledger
+    pub fn record_withdrawal(&mut self, now: i64, vault_balance: u64, amount: u64) -> Result<()> {
+        check_reserve(vault_balance, self.reserve_percentage, self.withdrawable_fees(), amount)?;
+        self.record_outflow(now, vault_balance, amount)?;
+        
+        // Keep the ledger in step with the vault
+        self.total_fees -= amount;
+        
+        Ok(())
+    }
+    
+    // Pay a stream out of its budget's reservation
+    pub fn record_budget_withdrawal(&mut self, now: i64, vault_balance: u64, amount: u64) -> Result<()> {
+        self.budgeted -= amount;
+        self.record_withdrawal(now, vault_balance, amount)
+    }
+}
+
+#[account]
+pub struct TreasuryMint {
+    pub mint: Pubkey,            // SPL mint tracked by this ledger
+    pub vault: Pubkey,           // Token account holding the mint, owned by the treasury PDA
+    pub total_fees: u64,         // Total collected fees in the mint's base units
+    pub reserve_percentage: u8,  // Percentage of the token vault to keep as reserves
+    pub large_withdrawal_threshold: u64, // Withdrawals at or above this must be queued
+    pub outflow_limit: OutflowLimit, // Rolling window cap on this mint's outflows
+    pub bump: u8,                // PDA bump
+}
+
+impl TreasuryMint {
+    // Validate a withdrawal against the reserve and take it off the ledger
+    pub fn record_withdrawal(&mut self, now: i64, vault_balance: u64, amount: u64) -> Result<()> {
+        check_reserve(vault_balance, self.reserve_percentage, self.total_fees, amount)?;
+        self.outflow_limit.record(now, vault_balance, amount)?;
+        
+        // Keep the ledger in step with the vault
+        self.total_fees -= amount;
+        
+        Ok(())
+    }
+}
+
+#[account]
+pub struct BuybackConfig {
+    pub token_mint: Pubkey,      // Governance token bought back and burned
+    pub token_vault: Pubkey,     // Treasury-owned token account receiving swapped tokens
+    pub swap_programs: Vec<Pubkey>, // Whitelisted swap venues
+    pub reference_rate: u64,     // Expected token base units per SOL
+    pub max_slippage_bps: u16,   // Max slippage against the reference rate (basis points)
+    pub epoch_cap: u64,          // Max lamports spent on buybacks per epoch
+    pub current_epoch: u64,      // Epoch the spend counter belongs to
+    pub epoch_spent: u64,        // Lamports spent in the current epoch
+    pub total_burned: u64,       // Total tokens burned
+    pub bump: u8,                // PDA bump
+}
+
+impl BuybackConfig {
+    pub const SPACE: usize = 8 + 32 + 32 + (4 + MAX_SWAP_VENUES * 32) + 8 + 2 + 8 + 8 + 8 + 8 + 1;
+    
+    // Least tokens a swap of `amount_in` lamports must return
+    pub fn min_amount_out(&self, amount_in: u64) -> u64 {
+        let expected = amount_in as u128 * self.reference_rate as u128
+            / anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL as u128;
+        
+        (expected * (10_000 - self.max_slippage_bps) as u128 / 10_000) as u64
+    }
+    
+    pub fn is_whitelisted(&self, program_id: &Pubkey) -> bool {
+        self.swap_programs.contains(program_id)
+    }
+    
+    // Venues sign with the treasury vault, so never whitelist a program that
+    // could move its lamports or tokens directly
+    pub fn validate_swap_programs(swap_programs: &[Pubkey]) -> Result<()> {
+        if swap_programs.len() > MAX_SWAP_VENUES {
+            return Err(error!(ErrorCode::InvalidTreasuryParameter));
+        }
+        
+        let restricted = [crate::ID, anchor_lang::system_program::ID, anchor_spl::token::ID];
+        for (i, program) in swap_programs.iter().enumerate() {
+            if swap_programs[..i].contains(program) || restricted.contains(program) {
+                return Err(error!(ErrorCode::UnsupportedSwapProgram));
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TreasuryConfigUpdate {
+    pub new_protocol_fee: Option<u8>,
+    pub new_reserve_percentage: Option<u8>,
+    pub new_rewards_share: Option<u8>,
+    pub new_outflow_cap: Option<u64>,
+    pub new_outflow_cap_percentage: Option<u8>,
+    pub new_outflow_window: Option<i64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TreasuryMintUpdate {
+    pub new_reserve_percentage: Option<u8>,
+    pub new_large_withdrawal_threshold: Option<u64>,
+    pub new_outflow_cap: Option<u64>,
+    pub new_outflow_cap_percentage: Option<u8>,
+    pub new_outflow_window: Option<i64>,
+}
+
+#[account]
+pub struct TreasuryMultisig {
+    pub signers: Vec<Pubkey>,    // Approving signer set
+    pub threshold: u8,           // Approvals required to execute
+    pub signer_set_seqno: u32,   // Bumped on every signer set change
+    pub proposal_count: u64,     // Number of proposals created
+    pub bump: u8,                // PDA bump
+}
+
+impl TreasuryMultisig {
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+    
+    // Count the votes cast by the current signer set
+    pub fn count_signers(&self, votes: &[Pubkey]) -> usize {
+        votes.iter().filter(|voter| self.is_signer(voter)).count()
+    }
+    
+    // Signer set must be non-empty, unique, and able to reach the threshold
+    pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        if signers.is_empty() || signers.len() > MAX_MULTISIG_SIGNERS {
+            return Err(error!(ErrorCode::InvalidMultisigConfig));
+        }
+        
+        for (i, signer) in signers.iter().enumerate() {
+            if signers[..i].contains(signer) {
+                return Err(error!(ErrorCode::InvalidMultisigConfig));
+            }
+        }
+        
+        if threshold == 0 || threshold as usize > signers.len() {
+            return Err(error!(ErrorCode::InvalidMultisigConfig));
+        }
+        
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalKind {
+    Withdraw { amount: u64, recipient: Pubkey, mint: Option<Pubkey>, memo: String },
+    ChangeSigners { signers: Vec<Pubkey>, threshold: u8 },
+    CreateBudget { name: String, allocation: u64 },
+    CreateStream { budget: Pubkey, recipient: Pubkey, amount: u64, start_time: i64, duration: i64 },
+}
+
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,        // Owning multisig
+    pub proposer: Pubkey,        // Signer who created the proposal
+    pub id: u64,                 // Proposal index
+    pub kind: ProposalKind,      // Action to execute
+    pub approvals: Vec<Pubkey>,  // Signers who approved
+    pub signer_set_seqno: u32,   // Signer set the proposal was made under
+    pub expires_at: i64,         // Proposal expiry timestamp
+    pub eta: i64,                // Large withdrawals can execute after this (0 = not queued)
+    pub executed: bool,          // Whether the proposal has been executed
+    pub cancellations: Vec<Pubkey>, // Signers who voted to cancel
+    pub cancelled: bool,         // Cancelled by a threshold of signers
+    pub bump: u8,                // PDA bump
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn treasury() -> Treasury {
+        Treasury {
+            admin: Pubkey::default(),
+            total_fees: 0,
+            total_deposits: 0,
+            protocol_fee: 5,
+            reserve_percentage: 0,
+            rewards_share: 50,
+            pending_rewards: 0,
+            budgeted: 0,
+            multisig_enabled: false,
+            outflow_limit: OutflowLimit::default(),
+            bump: 0,
+        }
+    }
+    
+    #[test]
+    fn withdrawals_leave_the_pending_reward_share() {
+        let mut treasury = treasury();
+        treasury.credit_fee(1_000);
+        assert_eq!(treasury.pending_rewards, 500);
+        
+        assert!(treasury.record_withdrawal(0, 1_000, 501).is_err());
+        treasury.record_withdrawal(0, 1_000, 500).unwrap();
+        
+        // The reward share can still be swept off the ledger
+        assert_eq!(treasury.total_fees, treasury.pending_rewards);
+        assert_eq!(treasury.withdrawable_fees(), 0);
+    }
+    
+    #[test]
+    fn deposits_take_no_reward_share() {
+        let mut treasury = treasury();
+        treasury.credit_deposit(1_000);
+        treasury.credit_fee(100);
+        
+        assert_eq!(treasury.total_fees, 1_100);
+        assert_eq!(treasury.total_deposits, 1_000);
+        assert_eq!(treasury.pending_rewards, 50);
+        assert_eq!(treasury.withdrawable_fees(), 1_050);
+    }
+    
+    #[test]
+    fn budget_reservations_leave_withdrawable_fees() {
+        let mut treasury = treasury();
+        treasury.credit_fee(1_000);
+        
+        // Half is owed to the reward pool, a budget can only reserve the rest
+        assert!(treasury.reserve_budget(501).is_err());
+        treasury.reserve_budget(300).unwrap();
+        assert!(treasury.record_withdrawal(0, 1_000, 201).is_err());
+        
+        // Streams are paid out of the reservation
+        treasury.record_budget_withdrawal(0, 1_000, 300).unwrap();
+        assert_eq!(treasury.budgeted, 0);
+        assert_eq!(treasury.withdrawable_fees(), 200);
+    }
+    
+    const DAY: i64 = 86_400;
+    const CAP: u64 = 1_000;
+    
+    #[test]
+    fn outflow_cap_holds_over_any_window() {
+        let mut limit = OutflowLimit::new(CAP, 0, DAY).unwrap();
+        let start = 1_700_000_000;
+        
+        // X at the start, then X/2 at half a window and again just before it ends
+        limit.record(start, 1_000_000, CAP).unwrap();
+        assert!(limit.record(start + DAY / 2, 1_000_000, CAP / 2).is_err());
+        assert!(limit.record(start + DAY - 1, 1_000_000, CAP / 2).is_err());
+        
+        // Nothing frees up until the first outflow's bucket is a full window old
+        assert!(limit.record(start + DAY, 1_000_000, 1).is_err());
+        
+        // Once the window has passed the full cap is available again
+        limit.record(start + 2 * DAY, 1_000_000, CAP).unwrap();
+    }
+    
+    #[test]
+    fn outflow_window_total_never_exceeds_cap() {
+        let mut limit = OutflowLimit::new(CAP, 0, DAY).unwrap();
+        let start = 1_700_000_000;
+        let mut history: Vec<(i64, u64)> = Vec::new();
+        
+        // Try to withdraw a little every 20 minutes for three days
+        for step in 0..(3 * 72) {
+            let now = start + step * 1_200;
+            if limit.record(now, 1_000_000, 150).is_ok() {
+                history.push((now, 150));
+            }
+            
+            let in_window: u64 = history
+                .iter()
+                .filter(|(time, _)| *time > now - DAY)
+                .map(|(_, amount)| amount)
+                .sum();
+            assert!(in_window <= CAP);
+        }
+        assert!(history.len() > 6);
+    }
+    
+    #[test]
+    fn outflow_percentage_cap_counts_the_window() {
+        let mut limit = OutflowLimit::new(0, 10, DAY).unwrap();
+        
+        // 10% of a 10_000 vault, spread over two withdrawals
+        limit.record(0, 10_000, 600).unwrap();
+        assert!(limit.record(DAY / 2, 9_400, 500).is_err());
+        limit.record(DAY / 2, 9_400, 400).unwrap();
+    }
+    
+    #[test]
+    fn resizing_the_window_keeps_recent_outflow() {
+        let mut limit = OutflowLimit::new(CAP, 0, DAY).unwrap();
+        limit.record(0, 1_000_000, CAP).unwrap();
+        
+        limit.set_window(DAY / 2, 2 * DAY);
+        assert_eq!(limit.window_outflow(), CAP);
+        assert!(limit.record(DAY, 1_000_000, 1).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+pub mod route;
+
+pub use route::*;
+
+// Swap venue used by treasury buybacks. Each venue gets an adapter that turns
+// the call into a CPI against that program.
+pub trait SwapAdapter<'info> {
+    // Swap up to `amount_in` lamports from the payer for at least `min_amount_out` tokens
+    fn swap_sol_for_token(
+        &self,
+        amount_in: u64,
+        min_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+}
\ No newline at end of file
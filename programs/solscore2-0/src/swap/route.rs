@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use crate::errors::*;
+use super::SwapAdapter;
+
+// Adapter for venues whitelisted in the buyback config. Whitelisted venues take
+// `(amount_in: u64, min_amount_out: u64)` after an 8-byte instruction selector,
+// the instruction is built here so the caller only supplies the venue's accounts.
+pub struct RouteAdapter<'a, 'info> {
+    pub swap_program: AccountInfo<'info>,
+    pub payer: Pubkey,
+    pub destination: Pubkey,
+    pub accounts: &'a [AccountInfo<'info>],
+    pub selector: [u8; 8],
+}
+
+impl<'a, 'info> RouteAdapter<'a, 'info> {
+    // Only the payer signs, and only the payer, the destination and accounts owned
+    // by the venue or the token program are passed writable
+    fn account_metas(&self) -> Result<Vec<AccountMeta>> {
+        let mut has_payer = false;
+        let mut has_destination = false;
+        let mut metas = Vec::with_capacity(self.accounts.len());
+        
+        for account in self.accounts {
+            let key = account.key();
+            
+            // Program state never goes to a venue
+            if *account.owner == crate::ID {
+                return Err(error!(ErrorCode::UnsupportedSwapProgram));
+            }
+            
+            let is_writable = if key == self.payer {
+                has_payer = true;
+                true
+            } else if key == self.destination {
+                has_destination = true;
+                true
+            } else {
+                account.is_writable
+                    && (*account.owner == self.swap_program.key() || *account.owner == anchor_spl::token::ID)
+            };
+            
+            metas.push(AccountMeta {
+                pubkey: key,
+                is_signer: key == self.payer,
+                is_writable,
+            });
+        }
+        
+        if !has_payer || !has_destination {
+            return Err(error!(ErrorCode::UnsupportedSwapProgram));
+        }
+        
+        Ok(metas)
+    }
+}
+
+impl<'a, 'info> SwapAdapter<'info> for RouteAdapter<'a, 'info> {
+    fn swap_sol_for_token(
+        &self,
+        amount_in: u64,
+        min_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let metas = self.account_metas()?;
+        
+        let mut data = self.selector.to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+        
+        let mut infos = self.accounts.to_vec();
+        infos.push(self.swap_program.clone());
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &Instruction {
+                program_id: self.swap_program.key(),
+                accounts: metas,
+                data,
+            },
+            &infos,
+            signer_seeds,
+        )?;
+        
+        Ok(())
+    }
+}
\ No newline at end of file
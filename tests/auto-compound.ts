@@ -0,0 +1,125 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  expectedReward,
+  expectError,
+  balance,
+  now,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('auto-compound', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+  let cranker: Keypair;
+  let stakeId: number;
+
+  const stakeAmount = sol(10);
+  const tipBps = 100; // 1%
+
+  function setAutoCompound(signer: Keypair, enabled: boolean) {
+    return ctx.program.methods
+      .setAutoCompound(new anchor.BN(stakeId), enabled)
+      .accountsPartial({
+        user: signer.publicKey,
+        stake: stakePda(ctx, user.publicKey, stakeId),
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  function compound() {
+    return ctx.program.methods
+      .compound(new anchor.BN(stakeId))
+      .accountsPartial({
+        cranker: cranker.publicKey,
+        stake: stakePda(ctx, user.publicKey, stakeId),
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+        rewardConfig: pda(ctx.program, "reward-config"),
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        referralStats: null,
+        stakeVault: pda(ctx.program, "stake-vault"),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([cranker])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, { compoundTipBps: tipBps });
+
+    user = await newUser(ctx.context);
+    cranker = await newUser(ctx.context);
+    await registerUser(ctx, user);
+    stakeId = await stake(ctx, user, stakeAmount);
+  });
+
+  // Stakes don't compound unless the owner opts in
+  it('Rejects compounding a stake that has not opted in', async () => {
+    await warp(ctx.context, 2 * DAY);
+    await expectError(compound(), 'AutoCompoundDisabled');
+  });
+
+  // Only the owner can flip the flag
+  it('Rejects toggling auto-compound on someone else\'s stake', async () => {
+    await expectError(setAutoCompound(cranker, true), 'ConstraintSeeds');
+  });
+
+  // Anyone can crank an opted-in stake, the reward grows the principal and the cranker is tipped
+  it('Compounds rewards into the principal and tips the cranker', async () => {
+    await setAutoCompound(user, true);
+
+    const stakeAccount = stakePda(ctx, user.publicKey, stakeId);
+    const before = await ctx.program.account.stake.fetch(stakeAccount);
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "stake-vault"));
+    const crankerBefore = await balance(ctx.context, cranker.publicKey);
+    const userBefore = await balance(ctx.context, user.publicKey);
+    const elapsed = (await now(ctx.context)) - before.lastClaimTime.toNumber();
+
+    await compound();
+
+    const after = await ctx.program.account.stake.fetch(stakeAccount);
+    const reward = expectedReward(stakeAmount.toNumber(), elapsed);
+    const tip = Math.floor((reward * tipBps) / 10_000);
+
+    expect(after.amount.sub(before.amount).toNumber()).to.be.approximately(reward - tip, 2);
+    expect(after.lastClaimTime.toNumber()).to.equal(await now(ctx.context));
+    expect(await balance(ctx.context, pda(ctx.program, "stake-vault"))).to.equal(
+      vaultBefore + after.amount.sub(before.amount).toNumber()
+    );
+    expect((await balance(ctx.context, cranker.publicKey)) - crankerBefore).to.be.approximately(tip, 1);
+
+    // Nothing is paid out to the owner
+    expect(await balance(ctx.context, user.publicKey)).to.equal(userBefore);
+  });
+
+  // Same cadence as a manual claim
+  it('Rejects compounding before the distribution frequency', async () => {
+    await warp(ctx.context, 3600);
+    await expectError(compound(), 'TooEarlyToClaim');
+  });
+
+  // Opting back out stops the crank
+  it('Stops compounding once disabled', async () => {
+    await setAutoCompound(user, false);
+    await warp(ctx.context, 2 * DAY);
+
+    await expectError(compound(), 'AutoCompoundDisabled');
+  });
+});
@@ -0,0 +1,208 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  newUser,
+  depositToTreasury,
+  withdrawFromTreasury,
+  queueAction,
+  fetchTreasury,
+  expectError,
+  balance,
+  now,
+  pda,
+  u64,
+  warp,
+  sol,
+} from './helpers';
+
+describe('treasury budgets and streams', () => {
+  let ctx: Ctx;
+  let contributor: Keypair;
+  let budget: PublicKey;
+
+  function createBudget(name: string, allocation: anchor.BN, admin: Keypair = ctx.admin, pendingAction: PublicKey | null = null) {
+    return ctx.program.methods
+      .createBudget(name, allocation)
+      .accountsPartial({
+        authority: admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        timelock: pda(ctx.program, "timelock"),
+        budget: pda(ctx.program, "budget", name),
+        pendingAction,
+        multisig: null,
+        proposal: null,
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  function streamPda(id: number) {
+    return pda(ctx.program, "stream", budget, u64(id));
+  }
+
+  async function createStream(recipient: PublicKey, amount: anchor.BN, duration: number): Promise<number> {
+    const { streamCount } = await ctx.program.account.budget.fetch(budget);
+    const id = streamCount.toNumber();
+
+    await ctx.program.methods
+      .createStream(recipient, amount, new anchor.BN(await now(ctx.context)), new anchor.BN(duration))
+      .accountsPartial({
+        authority: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        budget,
+        stream: streamPda(id),
+        multisig: null,
+        proposal: null,
+      })
+      .rpc();
+
+    return id;
+  }
+
+  function withdrawStream(recipient: Keypair, id: number) {
+    return ctx.program.methods
+      .withdrawStream()
+      .accountsPartial({
+        recipient: recipient.publicKey,
+        budget,
+        stream: streamPda(id),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([recipient])
+      .rpc();
+  }
+
+  function cancelStream(id: number) {
+    return ctx.program.methods
+      .cancelStream()
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        budget,
+        stream: streamPda(id),
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initTimelock(ctx, DAY, sol(10));
+    await depositToTreasury(ctx, sol(20));
+
+    contributor = await newUser(ctx.context);
+    budget = pda(ctx.program, "budget", "grants");
+  });
+
+  // Budgets need a name and an allocation, and only the admin creates them
+  it('Creates a named budget', async () => {
+    await expectError(createBudget("", sol(8)), 'InvalidBudgetParameter');
+    await expectError(createBudget("grants", sol(0)), 'InvalidBudgetParameter');
+    await expectError(createBudget("grants", sol(10)), 'RequiresTimelock');
+
+    const outsider = await newUser(ctx.context);
+    await expectError(createBudget("grants", sol(8), outsider), 'UnauthorizedAccess');
+
+    await createBudget("grants", sol(8));
+
+    const account = await ctx.program.account.budget.fetch(budget);
+    expect(account.name).to.equal("grants");
+    expect(account.allocation.toString()).to.equal(sol(8).toString());
+    expect(account.committed.toNumber()).to.equal(0);
+    expect((await fetchTreasury(ctx)).budgeted.toString()).to.equal(sol(8).toString());
+  });
+
+  // Allocations are held back from withdrawals and from other budgets
+  it('Reserves budget allocations on the ledger', async () => {
+    await createBudget("ops", sol(9));
+    expect((await fetchTreasury(ctx)).budgeted.toString()).to.equal(sol(17).toString());
+
+    await expectError(withdrawFromTreasury(ctx, sol(4), ctx.admin.publicKey), 'ExceedsWithdrawalLimit');
+    await expectError(createBudget("more", sol(4)), 'ExceedsWithdrawalLimit');
+  });
+
+  // Streams have to fit the allocation
+  it('Rejects streams the budget would not allow', async () => {
+    await expectError(createStream(contributor.publicKey, sol(1), 0), 'InvalidBudgetParameter');
+    await expectError(createStream(contributor.publicKey, sol(9), 4 * DAY), 'ExceedsBudget');
+  });
+
+  // Recipients withdraw what has vested linearly so far
+  it('Streams a grant linearly to the recipient', async () => {
+    const id = await createStream(contributor.publicKey, sol(4), 4 * DAY);
+    expect((await ctx.program.account.budget.fetch(budget)).committed.toString()).to.equal(sol(4).toString());
+
+    await expectError(withdrawStream(contributor, id), 'NothingVested');
+
+    await warp(ctx.context, DAY);
+    const outsider = await newUser(ctx.context);
+    await expectError(withdrawStream(outsider, id), 'UnauthorizedAccess');
+
+    const before = await balance(ctx.context, contributor.publicKey);
+    await withdrawStream(contributor, id);
+    expect((await balance(ctx.context, contributor.publicKey)) - before).to.equal(sol(1).toNumber());
+
+    const account = await ctx.program.account.budget.fetch(budget);
+    expect(account.spent.toString()).to.equal(sol(1).toString());
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(sol(19).toString());
+    expect(treasury.budgeted.toString()).to.equal(sol(16).toString());
+  });
+
+  // Cancelling keeps what vested withdrawable and returns the rest to the budget
+  it('Cancels a stream and releases the unvested remainder', async () => {
+    await warp(ctx.context, DAY);
+    await cancelStream(0);
+
+    const stream = await ctx.program.account.grantStream.fetch(streamPda(0));
+    expect(stream.cancelled).to.be.true;
+    expect(stream.totalAmount.toString()).to.equal(sol(2).toString());
+    expect((await ctx.program.account.budget.fetch(budget)).committed.toString()).to.equal(sol(2).toString());
+
+    await warp(ctx.context, DAY);
+    const before = await balance(ctx.context, contributor.publicKey);
+    await withdrawStream(contributor, 0);
+    expect((await balance(ctx.context, contributor.publicKey)) - before).to.equal(sol(1).toNumber());
+
+    await warp(ctx.context, DAY);
+    await expectError(withdrawStream(contributor, 0), 'NothingVested');
+    await expectError(cancelStream(0), 'StreamCancelled');
+  });
+
+  // The released allocation can fund new streams
+  it('Reuses the released allocation', async () => {
+    await expectError(createStream(contributor.publicKey, sol(7), DAY), 'ExceedsBudget');
+    await createStream(contributor.publicKey, sol(6), DAY);
+
+    expect((await ctx.program.account.budget.fetch(budget)).committed.toString()).to.equal(sol(8).toString());
+  });
+
+  // Allocations at the threshold wait out a queued action, however they would be streamed
+  it('Holds large budgets for the timelock delay', async () => {
+    await depositToTreasury(ctx, sol(20));
+    const id = await queueAction(ctx, { createBudget: { name: "season", allocation: sol(10) } });
+    const pendingAction = pda(ctx.program, "pending-action", u64(id));
+
+    await expectError(createBudget("season", sol(10), ctx.admin, pendingAction), 'TimelockNotReady');
+    await warp(ctx.context, DAY);
+    await expectError(createBudget("season", sol(11), ctx.admin, pendingAction), 'ApprovalMismatch');
+
+    await createBudget("season", sol(10), ctx.admin, pendingAction);
+    expect((await ctx.program.account.pendingAction.fetch(pendingAction)).executed).to.be.true;
+    expect((await fetchTreasury(ctx)).budgeted.toString()).to.equal(sol(25).toString());
+
+    await expectError(createBudget("season2", sol(10), ctx.admin, pendingAction), 'ActionAlreadyExecuted');
+  });
+});
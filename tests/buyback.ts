@@ -0,0 +1,232 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { MockAmm } from '../target/types/mock_amm';
+import { expect } from 'chai';
+import { Clock } from 'solana-bankrun';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { MintLayout } from '@solana/spl-token';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initStakeConfig,
+  initRewards,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  depositToTreasury,
+  queueAction,
+  executeAction,
+  createMint,
+  mintTokens,
+  tokenBalance,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+const MOCK_AMM_IDL = require('../target/idl/mock_amm.json');
+
+describe('buyback', () => {
+  let ctx: Ctx;
+  let amm: Program<MockAmm>;
+  let mint: PublicKey;
+  let pool: PublicKey;
+  let poolTokens: PublicKey;
+  let tokenVault: PublicKey;
+
+  // 1 token base unit per 1000 lamports
+  const rateNumerator = 1;
+  const rateDenominator = 1_000;
+
+  const referenceRate = sol(1).muln(rateNumerator).divn(rateDenominator);
+
+  function tokensFor(lamports: anchor.BN): anchor.BN {
+    return lamports.muln(rateNumerator).divn(rateDenominator);
+  }
+
+  function initBuyback(overrides: Record<string, unknown> = {}) {
+    return ctx.program.methods
+      .initializeBuyback({
+        referenceRate,
+        maxSlippageBps: 100,
+        epochCap: sol(5),
+        swapPrograms: [amm.programId],
+        ...overrides,
+      } as any)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        tokenMint: mint,
+        buybackConfig: pda(ctx.program, "buyback-config"),
+        tokenVault,
+      })
+      .rpc();
+  }
+
+  // The venue's swap instruction, the buyback takes its selector and accounts
+  function route(amountIn: anchor.BN): Promise<TransactionInstruction> {
+    return amm.methods
+      .swapSolForToken(amountIn, new anchor.BN(0))
+      .accountsPartial({
+        payer: pda(ctx.program, "treasury-vault"),
+        pool,
+        poolTokenAccount: poolTokens,
+        destination: tokenVault,
+      })
+      .instruction();
+  }
+
+  async function buyback(amountIn: anchor.BN, swapProgram = amm.programId, extraAccounts: PublicKey[] = []) {
+    const ix = await route(amountIn);
+    const accounts = [
+      ...ix.keys.map((key) => ({ ...key, isSigner: false })),
+      ...extraAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ];
+
+    return ctx.program.methods
+      .executeBuyback(amountIn, Array.from(ix.data.subarray(0, 8)))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        timelock: pda(ctx.program, "timelock"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        buybackConfig: pda(ctx.program, "buyback-config"),
+        tokenMint: mint,
+        tokenVault,
+        swapProgram,
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .remainingAccounts(accounts)
+      .rpc();
+  }
+
+  async function mintSupply(): Promise<bigint> {
+    const account = await ctx.context.banksClient.getAccount(mint);
+    return MintLayout.decode(Buffer.from(account.data)).supply;
+  }
+
+  async function fetchBuyback() {
+    return ctx.program.account.buybackConfig.fetch(pda(ctx.program, "buyback-config"));
+  }
+
+  // Spend caps are per epoch, move the clock into the next one
+  async function nextEpoch() {
+    const clock = await ctx.context.banksClient.getClock();
+    ctx.context.setClock(
+      new Clock(clock.slot, clock.unixTimestamp, clock.epoch + BigInt(1), clock.leaderScheduleEpoch, clock.unixTimestamp)
+    );
+    await warp(ctx.context, 0);
+  }
+
+  before(async () => {
+    ctx = await setup();
+    amm = new Program<MockAmm>(MOCK_AMM_IDL, ctx.provider);
+
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx, {}, sol(0));
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initTimelock(ctx, DAY, sol(10));
+    await depositToTreasury(ctx, sol(20));
+
+    mint = await createMint(ctx, ctx.admin.publicKey, 6);
+    tokenVault = pda(ctx.program, "buyback-vault", mint);
+    pool = PublicKey.findProgramAddressSync([Buffer.from("pool"), mint.toBuffer()], amm.programId)[0];
+    poolTokens = PublicKey.findProgramAddressSync([Buffer.from("pool-tokens"), mint.toBuffer()], amm.programId)[0];
+
+    await amm.methods
+      .initializePool(new anchor.BN(rateNumerator), new anchor.BN(rateDenominator))
+      .accountsPartial({ admin: ctx.admin.publicKey, mint, pool, poolTokenAccount: poolTokens })
+      .rpc();
+    await mintTokens(ctx, mint, poolTokens, 1_000_000_000);
+  });
+
+  // Slippage, caps and venues are validated up front
+  it('Rejects invalid buyback configs', async () => {
+    await expectError(initBuyback({ referenceRate: new anchor.BN(0) }), 'InvalidTreasuryParameter');
+    await expectError(initBuyback({ maxSlippageBps: 10_001 }), 'InvalidTreasuryParameter');
+    await expectError(initBuyback({ epochCap: sol(0) }), 'InvalidTreasuryParameter');
+    await expectError(initBuyback({ swapPrograms: [ctx.program.programId] }), 'UnsupportedSwapProgram');
+    await expectError(initBuyback({ swapPrograms: [amm.programId, amm.programId] }), 'UnsupportedSwapProgram');
+
+    await initBuyback();
+    const config = await fetchBuyback();
+    expect(config.tokenVault.toString()).to.equal(tokenVault.toString());
+    expect(config.swapPrograms.map((program) => program.toString())).to.deep.equal([amm.programId.toString()]);
+  });
+
+  // Treasury SOL buys the token through the venue and the tokens are burned
+  it('Swaps treasury SOL for tokens and burns them', async () => {
+    const amountIn = sol(2);
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const supplyBefore = await mintSupply();
+
+    await buyback(amountIn);
+
+    expect(vaultBefore - (await balance(ctx.context, pda(ctx.program, "treasury-vault")))).to.equal(amountIn.toNumber());
+    expect(await tokenBalance(ctx, tokenVault)).to.equal(0);
+    expect((supplyBefore - (await mintSupply())).toString()).to.equal(tokensFor(amountIn).toString());
+
+    const config = await fetchBuyback();
+    expect(config.epochSpent.toString()).to.equal(amountIn.toString());
+    expect(config.totalBurned.toString()).to.equal(tokensFor(amountIn).toString());
+    expect((await fetchTreasury(ctx)).totalFees.toString()).to.equal(sol(18).toString());
+  });
+
+  // The minimum out comes from the configured rate, which only changes through the timelock
+  it('Rejects swaps below the reference rate', async () => {
+    await expectError(queueAction(ctx, { setBuybackRate: { referenceRate: new anchor.BN(0) } }), 'InvalidTreasuryParameter');
+
+    const raise = await queueAction(ctx, { setBuybackRate: { referenceRate: referenceRate.muln(2) } });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, raise, { buybackConfig: pda(ctx.program, "buyback-config") });
+    await expectError(buyback(sol(1)), 'SlippageExceeded');
+
+    const restore = await queueAction(ctx, { setBuybackRate: { referenceRate } });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, restore, { buybackConfig: pda(ctx.program, "buyback-config") });
+    expect((await fetchBuyback()).referenceRate.toString()).to.equal(referenceRate.toString());
+  });
+
+  // Program accounts can't be handed to the venue
+  it('Rejects program accounts in the route', async () => {
+    await expectError(buyback(sol(1), amm.programId, [pda(ctx.program, "treasury")]), 'UnsupportedSwapProgram');
+  });
+
+  // Only whitelisted venues, and large buybacks go through the timelock
+  it('Rejects unlisted venues and large buybacks', async () => {
+    await expectError(buyback(sol(1), SystemProgram.programId), 'UnsupportedSwapProgram');
+    await expectError(buyback(sol(10)), 'RequiresTimelock');
+  });
+
+  // Spending is capped per epoch
+  it('Caps buyback spend per epoch', async () => {
+    await expectError(buyback(sol(4)), 'BuybackCapExceeded');
+
+    await nextEpoch();
+    await buyback(sol(4));
+    expect((await fetchBuyback()).epochSpent.toString()).to.equal(sol(4).toString());
+  });
+
+  // Venue changes go through the timelock
+  it('Updates the venue whitelist through the timelock', async () => {
+    await expectError(
+      queueAction(ctx, { setSwapPrograms: { swapPrograms: [SystemProgram.programId] } }),
+      'UnsupportedSwapProgram'
+    );
+
+    const id = await queueAction(ctx, { setSwapPrograms: { swapPrograms: [] } });
+    await warp(ctx.context, DAY);
+    await expectError(executeAction(ctx, id), 'BuybackConfigRequired');
+    await executeAction(ctx, id, { buybackConfig: pda(ctx.program, "buyback-config") });
+
+    expect((await fetchBuyback()).swapPrograms).to.be.empty;
+    await expectError(buyback(sol(1)), 'UnsupportedSwapProgram');
+  });
+});
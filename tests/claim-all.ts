@@ -0,0 +1,133 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  claimAccounts,
+  expectedReward,
+  expectError,
+  balance,
+  now,
+  warp,
+  sol,
+} from './helpers';
+
+describe('claim_all', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+
+  const stakeAmount = sol(10);
+
+  function claimAll(signer: Keypair, stakeIds: number[], stakeAccounts: PublicKey[]) {
+    return ctx.program.methods
+      .claimAll(stakeIds.map((id) => new anchor.BN(id)))
+      .accountsPartial(claimAccounts(ctx, signer.publicKey))
+      .remainingAccounts(stakeAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+      .signers([signer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx);
+
+    user = await newUser(ctx.context);
+    await registerUser(ctx, user);
+    await stake(ctx, user, stakeAmount);
+    await stake(ctx, user, stakeAmount);
+  });
+
+  // Both stakes pay out in a single transaction
+  it('Claims rewards across all due stakes', async () => {
+    await warp(ctx.context, 2 * DAY);
+
+    const before = await balance(ctx.context, user.publicKey);
+    const stakes = [stakePda(ctx, user.publicKey, 0), stakePda(ctx, user.publicKey, 1)];
+
+    await claimAll(user, [0, 1], stakes);
+
+    const after = await balance(ctx.context, user.publicKey);
+    const expected = 2 * expectedReward(stakeAmount.toNumber(), 2 * DAY);
+    expect(after - before).to.be.approximately(expected, 2);
+
+    const claimedAt = await now(ctx.context);
+    for (const stakeAccount of stakes) {
+      const data = await ctx.program.account.stake.fetch(stakeAccount);
+      expect(data.lastClaimTime.toNumber()).to.equal(claimedAt);
+    }
+
+    const pool = await ctx.program.account.rewardPool.fetch(
+      claimAccounts(ctx, user.publicKey).rewardPool
+    );
+    expect(pool.distributedRewards.toNumber()).to.equal(after - before);
+  });
+
+  // A stake that isn't due doesn't block the others
+  it('Skips stakes that are not due yet', async () => {
+    await warp(ctx.context, 2 * DAY);
+    await stake(ctx, user, stakeAmount);
+    await warp(ctx.context, 3600);
+
+    const newStake = stakePda(ctx, user.publicKey, 2);
+    const stakedAt = (await ctx.program.account.stake.fetch(newStake)).lastClaimTime.toNumber();
+
+    const before = await balance(ctx.context, user.publicKey);
+    await claimAll(user, [0, 1, 2], [stakePda(ctx, user.publicKey, 0), stakePda(ctx, user.publicKey, 1), newStake]);
+    const after = await balance(ctx.context, user.publicKey);
+
+    expect(after - before).to.be.approximately(2 * expectedReward(stakeAmount.toNumber(), 2 * DAY + 3600), 2);
+
+    const skipped = await ctx.program.account.stake.fetch(newStake);
+    expect(skipped.lastClaimTime.toNumber()).to.equal(stakedAt);
+  });
+
+  // Nothing due at all
+  it('Fails when no stake has rewards due', async () => {
+    await warp(ctx.context, 60);
+
+    await expectError(
+      claimAll(user, [0, 1], [stakePda(ctx, user.publicKey, 0), stakePda(ctx, user.publicKey, 1)]),
+      'NoRewardsAvailable'
+    );
+  });
+
+  // The same stake can't be paid twice in one claim
+  it('Rejects duplicate stake accounts', async () => {
+    await warp(ctx.context, 2 * DAY);
+    const stakeAccount = stakePda(ctx, user.publicKey, 0);
+
+    await expectError(claimAll(user, [0, 0], [stakeAccount, stakeAccount]), 'InvalidStakeAccounts');
+  });
+
+  // Every stake account needs a matching id
+  it('Rejects a stake id count mismatch', async () => {
+    await expectError(
+      claimAll(user, [0, 1], [stakePda(ctx, user.publicKey, 0)]),
+      'InvalidStakeAccounts'
+    );
+    await expectError(claimAll(user, [], []), 'InvalidStakeAccounts');
+  });
+
+  // The account has to be the PDA for the id it's paired with
+  it('Rejects stake accounts that do not match their ids', async () => {
+    await expectError(
+      claimAll(user, [1, 0], [stakePda(ctx, user.publicKey, 0), stakePda(ctx, user.publicKey, 1)]),
+      'InvalidStakeAccounts'
+    );
+  });
+
+  // Someone else's stake can't be claimed
+  it("Rejects another user's stake", async () => {
+    const other = await newUser(ctx.context);
+    await registerUser(ctx, other);
+
+    await expectError(claimAll(other, [0], [stakePda(ctx, user.publicKey, 0)]), 'UnauthorizedAccess');
+  });
+});
@@ -0,0 +1,203 @@
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  createMarket,
+  proposeResolution,
+  finalizeResolution,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('resolution disputes', () => {
+  let ctx: Ctx;
+  let council: Keypair[];
+  let proposer: Keypair;
+  let disputer: Keypair;
+  let markets: PublicKey[];
+
+  const outcomes = ["Home", "Draw", "Away"];
+
+  function dispute(market: PublicKey, outcome: number, bond = sol(2)) {
+    return ctx.program.methods
+      .dispute(outcome, bond)
+      .accountsPartial({
+        disputer: disputer.publicKey,
+        market,
+        resolution: pda(ctx.program, "resolution", market),
+        bondVault: pda(ctx.program, "bond-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([disputer])
+      .rpc();
+  }
+
+  function resolveDispute(market: PublicKey, outcome: number, signer: Keypair = council[0]) {
+    return ctx.program.methods
+      .resolveDispute(outcome)
+      .accountsPartial({
+        councilMember: signer.publicKey,
+        marketConfig: pda(ctx.program, "market-config"),
+        market,
+        resolution: pda(ctx.program, "resolution", market),
+        proposer: proposer.publicKey,
+        disputer: disputer.publicKey,
+        bondVault: pda(ctx.program, "bond-vault", market),
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  // Two of the three council members rule for the outcome
+  async function rule(market: PublicKey, outcome: number) {
+    await resolveDispute(market, outcome, council[0]);
+    await resolveDispute(market, outcome, council[1]);
+  }
+
+  async function fetchResolution(market: PublicKey) {
+    return ctx.program.account.resolutionProposal.fetch(pda(ctx.program, "resolution", market));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    council = [await newUser(ctx.context), await newUser(ctx.context), await newUser(ctx.context)];
+    proposer = await newUser(ctx.context);
+    disputer = await newUser(ctx.context);
+
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await expectError(
+      initMarketConfig(ctx, { council: council.map((member) => member.publicKey), councilThreshold: 4 }),
+      'InvalidMarketParameter'
+    );
+    await initMarketConfig(ctx, { council: council.map((member) => member.publicKey), councilThreshold: 2 });
+    await initOracle(ctx);
+    await createFixture(ctx);
+
+    markets = [];
+    for (let i = 0; i < 4; i++) {
+      markets.push(await createMarket(ctx, { outcomes }));
+    }
+    await warp(ctx.context, DAY);
+  });
+
+  // Proposals need the minimum bond, disputes must pick another outcome and outbid it
+  it('Rejects underbonded proposals and disputes', async () => {
+    const [market] = markets;
+    await expectError(proposeResolution(ctx, market, 0, sol(0.5), proposer), 'InsufficientBond');
+    await proposeResolution(ctx, market, 0, sol(1), proposer);
+
+    await expectError(dispute(market, 0), 'InvalidOutcome');
+    await expectError(dispute(market, 3), 'InvalidOutcome');
+    await expectError(dispute(market, 1, sol(1)), 'InsufficientBond');
+    await expectError(resolveDispute(market, 1), 'NotDisputed');
+  });
+
+  // A dispute locks the proposal until the council steps in
+  it('Escalates a disputed proposal', async () => {
+    const [market] = markets;
+    await dispute(market, 1);
+
+    const resolution = await fetchResolution(market);
+    expect(resolution.status).to.deep.equal({ disputed: {} });
+    expect(resolution.disputer.toString()).to.equal(disputer.publicKey.toString());
+    expect(resolution.disputedOutcome).to.equal(1);
+    expect(await balance(ctx.context, pda(ctx.program, "bond-vault", market))).to.equal(sol(3).toNumber());
+
+    await expectError(dispute(market, 2, sol(3)), 'AlreadyDisputed');
+
+    await warp(ctx.context, DAY + 1);
+    await expectError(finalizeResolution(ctx, market, proposer.publicKey), 'AlreadyDisputed');
+    await expectError(resolveDispute(market, 1, proposer), 'UnauthorizedAccess');
+  });
+
+  // A single council member can't settle the dispute alone
+  it('Waits for the council threshold to agree', async () => {
+    const [market] = markets;
+    await expectError(resolveDispute(market, 3), 'InvalidOutcome');
+
+    await resolveDispute(market, 1, council[0]);
+    await expectError(resolveDispute(market, 0, council[0]), 'AlreadyVoted');
+
+    // Split votes leave the market disputed
+    await resolveDispute(market, 0, council[2]);
+    expect((await fetchResolution(market)).status).to.deep.equal({ disputed: {} });
+    expect((await ctx.program.account.market.fetch(market)).status).to.deep.equal({ open: {} });
+  });
+
+  // The disputer was right and takes both bonds
+  it('Pays both bonds to a successful disputer', async () => {
+    const [market] = markets;
+    const before = await balance(ctx.context, disputer.publicKey);
+    await resolveDispute(market, 1, council[1]);
+
+    expect((await balance(ctx.context, disputer.publicKey)) - before).to.equal(sol(3).toNumber());
+    expect((await fetchResolution(market)).status).to.deep.equal({ finalized: {} });
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.status).to.deep.equal({ resolved: {} });
+    expect(account.winningOutcome).to.equal(1);
+
+    await warp(ctx.context, 0);
+    await expectError(resolveDispute(market, 1), 'MarketAlreadyResolved');
+  });
+
+  // The proposal stands and the proposer takes both bonds
+  it('Pays both bonds to the proposer when the proposal stands', async () => {
+    const market = markets[1];
+    await proposeResolution(ctx, market, 0, sol(1), proposer);
+    await dispute(market, 1);
+
+    const before = await balance(ctx.context, proposer.publicKey);
+    await rule(market, 0);
+
+    expect((await balance(ctx.context, proposer.publicKey)) - before).to.equal(sol(3).toNumber());
+    expect((await ctx.program.account.market.fetch(market)).winningOutcome).to.equal(0);
+  });
+
+  // Neither side was right, so both bonds are slashed to the treasury
+  it('Slashes both bonds when neither side was right', async () => {
+    const market = markets[2];
+    await proposeResolution(ctx, market, 0, sol(1), proposer);
+    await dispute(market, 1);
+
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+    await rule(market, 2);
+
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - vaultBefore).to.equal(sol(3).toNumber());
+    expect((await fetchTreasury(ctx)).totalFees.toNumber() - feesBefore).to.equal(sol(3).toNumber());
+    expect(await balance(ctx.context, pda(ctx.program, "bond-vault", market))).to.equal(0);
+  });
+
+  // Once the window has passed the proposal can only be finalized
+  it('Rejects disputes after the window closes', async () => {
+    const market = markets[3];
+    await proposeResolution(ctx, market, 2, sol(1), proposer);
+    await warp(ctx.context, DAY + 1);
+
+    await expectError(dispute(market, 1), 'DisputeWindowClosed');
+
+    const before = await balance(ctx.context, proposer.publicKey);
+    await finalizeResolution(ctx, market, proposer.publicKey);
+    expect((await balance(ctx.context, proposer.publicKey)) - before).to.equal(sol(1).toNumber());
+  });
+});
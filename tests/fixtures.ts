@@ -0,0 +1,205 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Ed25519Program, Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY, TransactionInstruction } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  SEASON_START,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  newUser,
+  createMarket,
+  expectError,
+  pda,
+  u64,
+} from './helpers';
+
+describe('signed fixtures', () => {
+  let ctx: Ctx;
+  let publisher: Keypair;
+  let relayer: Keypair;
+
+  const kickoff = SEASON_START + 3 * DAY;
+
+  function payload(overrides: Partial<Record<string, unknown>> = {}) {
+    return {
+      fixtureId: new anchor.BN(1),
+      sequence: 1,
+      gameweek: 1,
+      homeTeam: 1,
+      awayTeam: 7,
+      kickoff: new anchor.BN(kickoff),
+      status: { scheduled: {} },
+      homeScore: null,
+      awayScore: null,
+      ...overrides,
+    };
+  }
+
+  function fixturePda(fixtureId: number): PublicKey {
+    return pda(ctx.program, "fixture", u64(fixtureId));
+  }
+
+  // The ed25519 verify instruction the relayer puts in front of ours
+  function signed(message: ReturnType<typeof payload>, signer: Keypair = publisher): TransactionInstruction {
+    return Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message: ctx.program.coder.types.encode("FixturePayload", message),
+    });
+  }
+
+  function createFixture(message: ReturnType<typeof payload>, verify: TransactionInstruction[] = [signed(message)]) {
+    return ctx.program.methods
+      .createFixture(message as any)
+      .accountsPartial({
+        relayer: relayer.publicKey,
+        oracleConfig: pda(ctx.program, "oracle-config"),
+        fixture: fixturePda(message.fixtureId.toNumber()),
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions(verify)
+      .signers([relayer])
+      .rpc();
+  }
+
+  function updateFixture(message: ReturnType<typeof payload>, fixtureId = message.fixtureId.toNumber()) {
+    return ctx.program.methods
+      .updateFixture(message as any)
+      .accountsPartial({
+        relayer: relayer.publicKey,
+        oracleConfig: pda(ctx.program, "oracle-config"),
+        fixture: fixturePda(fixtureId),
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([signed(message)])
+      .signers([relayer])
+      .rpc();
+  }
+
+  async function fetchFixture(fixtureId: number) {
+    return ctx.program.account.fixture.fetch(fixturePda(fixtureId));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    publisher = await newUser(ctx.context);
+    relayer = await newUser(ctx.context);
+
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+    await ctx.program.methods
+      .initializeOracle(publisher.publicKey)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        globalState: pda(ctx.program, "fpl-global"),
+        oracleConfig: pda(ctx.program, "oracle-config"),
+      })
+      .rpc();
+  });
+
+  // The payload has to be verified for the publisher's key over exactly these bytes
+  it('Rejects payloads the publisher did not sign', async () => {
+    const message = payload();
+    await expectError(createFixture(message, []), 'InvalidSignature');
+    await expectError(createFixture(message, [signed(message, relayer)]), 'InvalidSignature');
+    await expectError(createFixture(message, [signed(payload({ awayTeam: 8 }))]), 'InvalidSignature');
+  });
+
+  // Signed payloads are still checked for consistency
+  it('Rejects inconsistent fixtures', async () => {
+    await expectError(createFixture(payload({ awayTeam: 1 })), 'InvalidFixture');
+    await expectError(createFixture(payload({ gameweek: 0 })), 'InvalidFixture');
+    await expectError(createFixture(payload({ status: { finished: {} } })), 'InvalidFixture');
+  });
+
+  // Anyone can relay a signed fixture
+  it('Creates a fixture from a signed payload', async () => {
+    await createFixture(payload());
+
+    const fixture = await fetchFixture(1);
+    expect(fixture.status).to.deep.equal({ scheduled: {} });
+    expect(fixture.kickoff.toNumber()).to.equal(kickoff);
+    expect(fixture.sequence).to.equal(1);
+    expect(fixture.history.length).to.equal(1);
+  });
+
+  // Updates apply in sequence and record status and kickoff changes
+  it('Applies signed updates in sequence', async () => {
+    await expectError(updateFixture(payload({ sequence: 2, status: { finished: {} }, homeScore: 1 })), 'InvalidFixture');
+    await expectError(updateFixture(payload({ homeTeam: 2 })), 'StaleFixtureUpdate');
+
+    await updateFixture(payload({ sequence: 2, kickoff: new anchor.BN(kickoff + DAY) }));
+    await updateFixture(payload({ sequence: 3, kickoff: new anchor.BN(kickoff + DAY), status: { live: {} } }));
+    await updateFixture(
+      payload({ sequence: 4, kickoff: new anchor.BN(kickoff + DAY), status: { finished: {} }, homeScore: 2, awayScore: 1 })
+    );
+
+    const fixture = await fetchFixture(1);
+    expect(fixture.status).to.deep.equal({ finished: {} });
+    expect(fixture.homeScore).to.equal(2);
+    expect(fixture.awayScore).to.equal(1);
+    expect(fixture.history.map((change) => Object.keys(change.status)[0])).to.deep.equal([
+      "scheduled",
+      "scheduled",
+      "live",
+      "finished",
+    ]);
+
+    // Replaying an older payload is rejected
+    await expectError(updateFixture(payload({ sequence: 3, status: { live: {} } })), 'StaleFixtureUpdate');
+  });
+
+  // A payload for one fixture can't be applied to another
+  it('Rejects payloads for a different fixture', async () => {
+    await createFixture(payload({ fixtureId: new anchor.BN(2) }));
+    await expectError(updateFixture(payload({ fixtureId: new anchor.BN(2), sequence: 2 }), 1), 'InvalidFixture');
+  });
+
+  // Markets only open on scheduled fixtures and close by kickoff
+  it('Creates markets on scheduled fixtures only', async () => {
+    await expectError(createMarket(ctx, { fixtureId: new anchor.BN(1) }), 'InvalidFixture');
+    await expectError(
+      createMarket(ctx, { fixtureId: new anchor.BN(2), closeTime: new anchor.BN(kickoff + 1) }),
+      'InvalidMarketParameter'
+    );
+
+    const market = await createMarket(ctx, { fixtureId: new anchor.BN(2), closeTime: new anchor.BN(kickoff) });
+    expect((await ctx.program.account.market.fetch(market)).fixtureId.toNumber()).to.equal(2);
+  });
+
+  // A postponed fixture lets anyone void its markets straight away
+  it('Voids markets on a postponed fixture', async () => {
+    const market = await createMarket(ctx, { fixtureId: new anchor.BN(2) });
+    const outsider = await newUser(ctx.context);
+    const voidMarket = () =>
+      ctx.program.methods
+        .voidMarket(false)
+        .accountsPartial({
+          caller: outsider.publicKey,
+          marketConfig: pda(ctx.program, "market-config"),
+          market,
+          fixture: fixturePda(2),
+          resolution: pda(ctx.program, "resolution", market),
+          proposer: null,
+          disputer: null,
+          bondVault: pda(ctx.program, "bond-vault", market),
+          marketVault: pda(ctx.program, "market-vault", market),
+          treasury: pda(ctx.program, "treasury"),
+          treasuryVault: pda(ctx.program, "treasury-vault"),
+          protocolState: pda(ctx.program, "protocol-state"),
+        })
+        .signers([outsider])
+        .rpc();
+
+    await expectError(voidMarket(), 'UnauthorizedAccess');
+
+    await updateFixture(payload({ fixtureId: new anchor.BN(2), sequence: 2, status: { postponed: {} } }));
+    await voidMarket();
+    expect((await ctx.program.account.market.fetch(market)).status).to.deep.equal({ voided: {} });
+  });
+});
@@ -0,0 +1,724 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Solscore } from '../target/types/solscore';
+import { expect } from 'chai';
+import { createHash } from 'crypto';
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from '@solana/web3.js';
+import {
+  TOKEN_PROGRAM_ID,
+  MINT_SIZE,
+  AccountLayout,
+  createInitializeMint2Instruction,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from '@solana/spl-token';
+import { startAnchor, ProgramTestContext, Clock } from 'solana-bankrun';
+import { BankrunProvider } from 'anchor-bankrun';
+
+const IDL = require('../target/idl/solscore.json');
+
+export const DAY = 86400;
+export const SEASON_START = 1_725_000_000;
+
+export type Ctx = {
+  context: ProgramTestContext;
+  provider: BankrunProvider;
+  program: Program<Solscore>;
+  admin: Keypair;
+};
+
+// Every suite gets its own bank so singleton configs don't leak between files
+export async function setup(): Promise<Ctx> {
+  const context = await startAnchor('.', [], []);
+  const provider = new BankrunProvider(context);
+  anchor.setProvider(provider);
+
+  const program = new Program<Solscore>(IDL, provider);
+
+  // Start every suite at a known time
+  await setTime(context, SEASON_START);
+
+  return { context, provider, program, admin: context.payer };
+}
+
+export function u64(value: number | bigint): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(BigInt(value));
+  return buf;
+}
+
+export function u32(value: number): Buffer {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(value);
+  return buf;
+}
+
+export function pda(program: Program<Solscore>, ...seeds: (string | Buffer | PublicKey)[]): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    seeds.map((seed) => {
+      if (typeof seed === 'string') return Buffer.from(seed);
+      if (seed instanceof PublicKey) return seed.toBuffer();
+      return seed;
+    }),
+    program.programId
+  )[0];
+}
+
+export function sol(amount: number): anchor.BN {
+  return new anchor.BN(Math.round(amount * LAMPORTS_PER_SOL));
+}
+
+export async function fund(context: ProgramTestContext, pubkey: PublicKey, lamports: number) {
+  context.setAccount(pubkey, {
+    lamports,
+    data: Buffer.alloc(0),
+    owner: SystemProgram.programId,
+    executable: false,
+  });
+}
+
+export async function newUser(context: ProgramTestContext, amount = 100): Promise<Keypair> {
+  const user = Keypair.generate();
+  await fund(context, user.publicKey, amount * LAMPORTS_PER_SOL);
+  return user;
+}
+
+export async function balance(context: ProgramTestContext, pubkey: PublicKey): Promise<number> {
+  return Number(await context.banksClient.getBalance(pubkey));
+}
+
+export async function now(context: ProgramTestContext): Promise<number> {
+  const clock = await context.banksClient.getClock();
+  return Number(clock.unixTimestamp);
+}
+
+async function setTime(context: ProgramTestContext, timestamp: number) {
+  const clock = await context.banksClient.getClock();
+
+  // Moving to the next slot gives a fresh blockhash, so identical transactions can be resent
+  context.warpToSlot(clock.slot + BigInt(1));
+  context.setClock(
+    new Clock(
+      clock.slot + BigInt(1),
+      clock.epochStartTimestamp,
+      clock.epoch,
+      clock.leaderScheduleEpoch,
+      BigInt(timestamp)
+    )
+  );
+}
+
+// Move the clock forward by `seconds`
+export async function warp(context: ProgramTestContext, seconds: number) {
+  await setTime(context, (await now(context)) + seconds);
+}
+
+// Expect the promise to fail with the given Anchor error code
+export async function expectError(promise: Promise<unknown>, code: string) {
+  let failed = false;
+
+  try {
+    await promise;
+  } catch (err) {
+    failed = true;
+    const logs: string[] = err.logs ?? err.transactionLogs ?? err.simulationResponse?.logs ?? [];
+    const message = [String(err), ...logs].join('\n');
+
+    if (err.error?.errorCode?.code !== code && !message.includes(`Error Code: ${code}`)) {
+      expect.fail(`expected ${code}, got: ${message}`);
+    }
+  }
+
+  if (!failed) {
+    expect.fail(`expected ${code}, but the transaction succeeded`);
+  }
+}
+
+// Initialize the FPL global state
+export async function initFpl(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}) {
+  await ctx.program.methods
+    .fplinit({
+      currentGameweek: 1,
+      gameweekDeadline: new anchor.BN(SEASON_START + 7 * DAY),
+      seasonStart: new anchor.BN(SEASON_START),
+      seasonEnd: new anchor.BN(SEASON_START + 300 * DAY),
+      apiUrl: "https://fantasy.premierleague.com/api/",
+      ...overrides,
+    } as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      globalState: pda(ctx.program, "fpl-global"),
+    })
+    .rpc();
+}
+
+// Initialize the stake config with 1 week and 30 day lock options
+export async function initStakeConfig(ctx: Ctx) {
+  await ctx.program.methods
+    .initializeStakeConfig({
+      minStakeAmount: sol(0.1),
+      maxStakeAmount: sol(1000),
+      earlyWithdrawalFee: 10, // 10%
+      lockOptions: [new anchor.BN(7 * DAY), new anchor.BN(30 * DAY)],
+    } as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      stakeConfig: pda(ctx.program, "stake-config"),
+    })
+    .rpc();
+}
+
+// Initialize the reward config and fund the reward pool
+export async function initRewards(
+  ctx: Ctx,
+  overrides: Partial<Record<string, unknown>> = {},
+  poolAmount = sol(100)
+) {
+  await ctx.program.methods
+    .initializeRewardConfig({
+      baseApy: 10,
+      scoreMultiplier: 0,
+      distributionFrequency: new anchor.BN(DAY),
+      compoundTipBps: 0,
+      vestingEnabled: false,
+      vestingDays: 0,
+      streakBonus: 0,
+      maxStreakBonus: 0,
+      referralShare: 0,
+      ...overrides,
+    } as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      rewardConfig: pda(ctx.program, "reward-config"),
+    })
+    .rpc();
+
+  await ctx.program.methods
+    .initializeRewardPool()
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      rewardConfig: pda(ctx.program, "reward-config"),
+      rewardPool: pda(ctx.program, "reward-pool"),
+      rewardVault: pda(ctx.program, "reward-vault"),
+    })
+    .rpc();
+
+  if (!poolAmount.isZero()) {
+    await ctx.program.methods
+      .distributeRewards(poolAmount)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+      })
+      .rpc();
+  }
+}
+
+// Initialize the treasury, no fee or caps unless overridden
+export async function initTreasury(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}) {
+  await ctx.program.methods
+    .treasuryinit({
+      protocolFee: 0,
+      reservePercentage: 0,
+      rewardsShare: 0,
+      outflowCap: new anchor.BN(0),
+      outflowCapPercentage: 0,
+      outflowWindow: new anchor.BN(DAY),
+      ...overrides,
+    } as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+    })
+    .rpc();
+}
+
+// Initialize the protocol state, the admin doubles as guardian unless one is given
+export async function initProtocol(ctx: Ctx, guardian: PublicKey = ctx.admin.publicKey) {
+  await ctx.program.methods
+    .initializeProtocolState(guardian)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .rpc();
+}
+
+export async function initTimelock(ctx: Ctx, delay = DAY, largeWithdrawalThreshold = sol(10)) {
+  await ctx.program.methods
+    .initializeTimelock(new anchor.BN(delay), largeWithdrawalThreshold)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      timelock: pda(ctx.program, "timelock"),
+    })
+    .rpc();
+}
+
+// Everything the staking and reward instructions need
+export async function initStakingProtocol(
+  ctx: Ctx,
+  rewards: Partial<Record<string, unknown>> = {},
+  treasury: Partial<Record<string, unknown>> = {}
+) {
+  await initFpl(ctx);
+  await initStakeConfig(ctx);
+  await initRewards(ctx, rewards);
+  await initTreasury(ctx, treasury);
+  await initProtocol(ctx);
+}
+
+export async function registerUser(ctx: Ctx, user: Keypair, referrer: PublicKey | null = null) {
+  const fplUser = pda(ctx.program, "fpl-user", user.publicKey);
+
+  await ctx.program.methods
+    .userreg("1234567", referrer)
+    .accountsPartial({
+      user: user.publicKey,
+      fplUser,
+      globalState: pda(ctx.program, "fpl-global"),
+      referralStats: referrer ? pda(ctx.program, "referral", referrer) : null,
+    })
+    .signers([user])
+    .rpc();
+
+  return fplUser;
+}
+
+// No instruction in this tree creates the per-user stake counter, so seed it
+// the way `create_stake` expects to find it
+async function ensureStakeCount(ctx: Ctx, user: PublicKey): Promise<number> {
+  const stakeCount = pda(ctx.program, "stake-count", user);
+  const existing = await ctx.context.banksClient.getAccount(stakeCount);
+  if (existing) {
+    return Number(Buffer.from(existing.data).readBigUInt64LE(8));
+  }
+
+  const discriminator = createHash('sha256').update('account:StakeCount').digest().subarray(0, 8);
+  ctx.context.setAccount(stakeCount, {
+    lamports: LAMPORTS_PER_SOL,
+    data: Buffer.concat([discriminator, u64(0)]),
+    owner: ctx.program.programId,
+    executable: false,
+  });
+
+  return 0;
+}
+
+export function stakePda(ctx: Ctx, user: PublicKey, stakeId: number): PublicKey {
+  return pda(ctx.program, "stake", user, u64(stakeId));
+}
+
+// Stake for the user and return the new stake's id
+export async function stake(ctx: Ctx, user: Keypair, amount: anchor.BN, lockPeriod = 7 * DAY): Promise<number> {
+  const stakeId = await ensureStakeCount(ctx, user.publicKey);
+
+  await ctx.program.methods
+    .stake(amount, new anchor.BN(lockPeriod))
+    .accountsPartial({
+      user: user.publicKey,
+      fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+      globalState: pda(ctx.program, "fpl-global"),
+      stake: stakePda(ctx, user.publicKey, stakeId),
+      stakeConfig: pda(ctx.program, "stake-config"),
+      stakeVault: pda(ctx.program, "stake-vault"),
+      stakeCount: pda(ctx.program, "stake-count", user.publicKey),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .signers([user])
+    .rpc();
+
+  return stakeId;
+}
+
+export function unstake(ctx: Ctx, user: Keypair, stakeId: number) {
+  return ctx.program.methods
+    .unstake()
+    .accountsPartial({
+      user: user.publicKey,
+      stake: stakePda(ctx, user.publicKey, stakeId),
+      fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+      stakeConfig: pda(ctx.program, "stake-config"),
+      stakeVault: pda(ctx.program, "stake-vault"),
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .signers([user])
+    .rpc();
+}
+
+type ClaimOptions = {
+  vesting?: boolean;
+  referrer?: PublicKey;
+};
+
+// Accounts shared by claim_rewards and claim_all
+export function claimAccounts(ctx: Ctx, user: PublicKey, options: ClaimOptions = {}) {
+  return {
+    user,
+    fplUser: pda(ctx.program, "fpl-user", user),
+    globalState: pda(ctx.program, "fpl-global"),
+    rewardConfig: pda(ctx.program, "reward-config"),
+    rewardPool: pda(ctx.program, "reward-pool"),
+    rewardVault: pda(ctx.program, "reward-vault"),
+    vestingSchedule: options.vesting ? pda(ctx.program, "vesting", user) : null,
+    referralStats: options.referrer ? pda(ctx.program, "referral", options.referrer) : null,
+    treasury: pda(ctx.program, "treasury"),
+    treasuryVault: pda(ctx.program, "treasury-vault"),
+    protocolState: pda(ctx.program, "protocol-state"),
+  };
+}
+
+export function claimRewards(ctx: Ctx, user: Keypair, stakeId: number, options: ClaimOptions = {}) {
+  return ctx.program.methods
+    .claimRewards(new anchor.BN(stakeId))
+    .accountsPartial({
+      ...claimAccounts(ctx, user.publicKey, options),
+      stake: stakePda(ctx, user.publicKey, stakeId),
+    })
+    .signers([user])
+    .rpc();
+}
+
+// Mirrors `calculate_base_reward` with no score or streak boost
+export function expectedReward(amount: number, seconds: number, apy = 10): number {
+  return Math.floor(amount * (Math.pow(1 + apy / 100, seconds / 31_536_000) - 1));
+}
+
+export async function fetchTreasury(ctx: Ctx) {
+  return ctx.program.account.treasury.fetch(pda(ctx.program, "treasury"));
+}
+
+// Deposit SOL into the treasury fee ledger
+export async function depositToTreasury(ctx: Ctx, amount: anchor.BN, depositor: Keypair = ctx.admin) {
+  await ctx.program.methods
+    .treasurydeposit(amount, null)
+    .accountsPartial({
+      depositor: depositor.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      treasuryMint: null,
+      depositorTokenAccount: null,
+      tokenVault: null,
+      tokenProgram: null,
+    })
+    .signers(depositor === ctx.admin ? [] : [depositor])
+    .rpc();
+}
+
+// Withdraw SOL from the treasury without the multisig
+export function withdrawFromTreasury(ctx: Ctx, amount: anchor.BN, recipient: PublicKey, admin: Keypair = ctx.admin) {
+  return ctx.program.methods
+    .withdraw(amount, null)
+    .accountsPartial({
+      admin: admin.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      timelock: pda(ctx.program, "timelock"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      recipient,
+      treasuryMint: null,
+      tokenVault: null,
+      recipientTokenAccount: null,
+      protocolState: pda(ctx.program, "protocol-state"),
+      tokenProgram: null,
+    })
+    .signers(admin === ctx.admin ? [] : [admin])
+    .rpc();
+}
+
+// Queue a timelocked action and return its id
+export async function queueAction(ctx: Ctx, action: object): Promise<number> {
+  const { actionCount } = await ctx.program.account.timelock.fetch(pda(ctx.program, "timelock"));
+  const id = actionCount.toNumber();
+
+  await ctx.program.methods
+    .queueAction(action as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      timelock: pda(ctx.program, "timelock"),
+      pendingAction: pda(ctx.program, "pending-action", u64(id)),
+    })
+    .rpc();
+
+  return id;
+}
+
+// Execute a queued action, `accounts` fills in whatever the action touches
+export function executeAction(ctx: Ctx, id: number, accounts: Partial<Record<string, PublicKey | null>> = {}) {
+  return ctx.program.methods
+    .executeAction()
+    .accountsPartial({
+      executor: ctx.admin.publicKey,
+      timelock: pda(ctx.program, "timelock"),
+      pendingAction: pda(ctx.program, "pending-action", u64(id)),
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      rewardConfig: pda(ctx.program, "reward-config"),
+      stakeConfig: pda(ctx.program, "stake-config"),
+      protocolState: pda(ctx.program, "protocol-state"),
+      buybackConfig: null,
+      recipient: ctx.admin.publicKey,
+      treasuryMint: null,
+      tokenVault: null,
+      recipientTokenAccount: null,
+      tokenProgram: null,
+      ...accounts,
+    })
+    .rpc();
+}
+
+// Initialize the market config, the admin doubles as resolver and a 1-of-1 dispute council
+export async function initMarketConfig(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}) {
+  await ctx.program.methods
+    .initializeMarketConfig({
+      resolver: ctx.admin.publicKey,
+      council: [ctx.admin.publicKey],
+      councilThreshold: 1,
+      minProposalBond: sol(1),
+      disputeWindow: new anchor.BN(DAY),
+      autoVoidDelay: new anchor.BN(7 * DAY),
+      creationBond: sol(1),
+      minCreatorStake: sol(10),
+      maxCreatorFeeBps: 500,
+      ...overrides,
+    } as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      treasury: pda(ctx.program, "treasury"),
+      marketConfig: pda(ctx.program, "market-config"),
+    })
+    .rpc();
+}
+
+// Initialize the oracle, the admin publishes unless told otherwise
+export async function initOracle(ctx: Ctx, publisher: PublicKey = ctx.admin.publicKey) {
+  await ctx.program.methods
+    .initializeOracle(publisher)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      globalState: pda(ctx.program, "fpl-global"),
+      oracleConfig: pda(ctx.program, "oracle-config"),
+    })
+    .rpc();
+}
+
+export function fixturePda(ctx: Ctx, fixtureId: number): PublicKey {
+  return pda(ctx.program, "fixture", u64(fixtureId));
+}
+
+// Publish a scheduled fixture signed by the oracle publisher. It kicks off at
+// the end of the season so any test market can close before it.
+export async function createFixture(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}, publisher: Keypair = ctx.admin) {
+  const payload = {
+    fixtureId: new anchor.BN(1),
+    sequence: 1,
+    gameweek: 1,
+    homeTeam: 1,
+    awayTeam: 7,
+    kickoff: new anchor.BN(SEASON_START + 300 * DAY),
+    status: { scheduled: {} },
+    homeScore: null,
+    awayScore: null,
+    ...overrides,
+  };
+
+  await ctx.program.methods
+    .createFixture(payload as any)
+    .accountsPartial({
+      relayer: ctx.admin.publicKey,
+      oracleConfig: pda(ctx.program, "oracle-config"),
+      fixture: fixturePda(ctx, (payload.fixtureId as anchor.BN).toNumber()),
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .preInstructions([
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: publisher.secretKey,
+        message: ctx.program.coder.types.encode("FixturePayload", payload),
+      }),
+    ])
+    .rpc();
+}
+
+export function marketPda(ctx: Ctx, id: number): PublicKey {
+  return pda(ctx.program, "market", u64(id));
+}
+
+// Market params for a yes/no parimutuel market closing in a day
+export async function marketParams(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}) {
+  return {
+    question: "Will Arsenal beat Chelsea?",
+    fixtureId: new anchor.BN(1),
+    outcomes: ["Yes", "No"],
+    mechanism: { parimutuel: {} },
+    closeTime: new anchor.BN((await now(ctx.context)) + DAY),
+    resolutionSource: "premierleague.com",
+    playerCondition: null,
+    limits: { maxPosition: new anchor.BN(0), maxPoolShareBps: 0, shareCapMinPool: new anchor.BN(0), minRegistrationAge: new anchor.BN(0) },
+    ...overrides,
+  };
+}
+
+// Create a protocol market and return its address
+export async function createMarket(ctx: Ctx, overrides: Partial<Record<string, unknown>> = {}): Promise<PublicKey> {
+  const { marketCount } = await ctx.program.account.marketConfig.fetch(pda(ctx.program, "market-config"));
+  const market = marketPda(ctx, marketCount.toNumber());
+  const params = await marketParams(ctx, overrides);
+
+  await ctx.program.methods
+    .createMarket(params as any)
+    .accountsPartial({
+      admin: ctx.admin.publicKey,
+      marketConfig: pda(ctx.program, "market-config"),
+      market,
+      marketVault: pda(ctx.program, "market-vault", market),
+      globalState: pda(ctx.program, "fpl-global"),
+      fixture: fixturePda(ctx, (params.fixtureId as anchor.BN).toNumber()),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .rpc();
+
+  return market;
+}
+
+export function positionPda(ctx: Ctx, market: PublicKey, user: PublicKey): PublicKey {
+  return pda(ctx.program, "position", market, user);
+}
+
+export async function openPosition(ctx: Ctx, market: PublicKey, user: Keypair) {
+  await ctx.program.methods
+    .openPosition()
+    .accountsPartial({
+      user: user.publicKey,
+      market,
+      position: positionPda(ctx, market, user.publicKey),
+    })
+    .signers([user])
+    .rpc();
+}
+
+export function placePosition(ctx: Ctx, market: PublicKey, user: Keypair, outcome: number, amount: anchor.BN, withFplUser = false) {
+  return ctx.program.methods
+    .placePosition(outcome, amount)
+    .accountsPartial({
+      user: user.publicKey,
+      market,
+      position: positionPda(ctx, market, user.publicKey),
+      fplUser: withFplUser ? pda(ctx.program, "fpl-user", user.publicKey) : null,
+      marketVault: pda(ctx.program, "market-vault", market),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .signers([user])
+    .rpc();
+}
+
+export function proposeResolution(ctx: Ctx, market: PublicKey, outcome: number, bond = sol(1), proposer: Keypair = ctx.admin) {
+  return ctx.program.methods
+    .proposeResolution(outcome, bond)
+    .accountsPartial({
+      proposer: proposer.publicKey,
+      marketConfig: pda(ctx.program, "market-config"),
+      market,
+      resolution: pda(ctx.program, "resolution", market),
+      bondVault: pda(ctx.program, "bond-vault", market),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .signers(proposer === ctx.admin ? [] : [proposer])
+    .rpc();
+}
+
+export function finalizeResolution(ctx: Ctx, market: PublicKey, proposer: PublicKey = ctx.admin.publicKey) {
+  return ctx.program.methods
+    .finalizeResolution()
+    .accountsPartial({
+      caller: ctx.admin.publicKey,
+      market,
+      resolution: pda(ctx.program, "resolution", market),
+      proposer,
+      bondVault: pda(ctx.program, "bond-vault", market),
+      marketVault: pda(ctx.program, "market-vault", market),
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .rpc();
+}
+
+// Resolve a closed market through an undisputed proposal
+export async function resolveMarket(ctx: Ctx, market: PublicKey, outcome: number) {
+  await proposeResolution(ctx, market, outcome);
+  const { disputeWindow } = await ctx.program.account.marketConfig.fetch(pda(ctx.program, "market-config"));
+  await warp(ctx.context, disputeWindow.toNumber() + 1);
+  await finalizeResolution(ctx, market);
+}
+
+export function claimWinnings(ctx: Ctx, market: PublicKey, user: Keypair) {
+  return ctx.program.methods
+    .claimWinnings()
+    .accountsPartial({
+      user: user.publicKey,
+      market,
+      position: positionPda(ctx, market, user.publicKey),
+      marketVault: pda(ctx.program, "market-vault", market),
+      treasury: pda(ctx.program, "treasury"),
+      treasuryVault: pda(ctx.program, "treasury-vault"),
+      protocolState: pda(ctx.program, "protocol-state"),
+    })
+    .signers([user])
+    .rpc();
+}
+
+// SPL helpers, bankrun has no RPC connection for the spl-token convenience wrappers
+
+export async function createMint(ctx: Ctx, authority: PublicKey, decimals = 9): Promise<PublicKey> {
+  const mint = Keypair.generate();
+  const lamports = await ctx.context.banksClient.getRent().then((rent) => rent.minimumBalance(BigInt(MINT_SIZE)));
+
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: ctx.admin.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: MINT_SIZE,
+      lamports: Number(lamports),
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    createInitializeMint2Instruction(mint.publicKey, decimals, authority, null)
+  );
+  await ctx.provider.sendAndConfirm(tx, [mint]);
+
+  return mint.publicKey;
+}
+
+export async function createTokenAccount(ctx: Ctx, mint: PublicKey, owner: PublicKey): Promise<PublicKey> {
+  const address = getAssociatedTokenAddressSync(mint, owner, true);
+
+  const tx = new Transaction().add(
+    createAssociatedTokenAccountIdempotentInstruction(ctx.admin.publicKey, address, owner, mint)
+  );
+  await ctx.provider.sendAndConfirm(tx);
+
+  return address;
+}
+
+// Mint tokens with the admin as mint authority
+export async function mintTokens(ctx: Ctx, mint: PublicKey, destination: PublicKey, amount: number) {
+  const tx = new Transaction().add(createMintToInstruction(mint, destination, ctx.admin.publicKey, amount));
+  await ctx.provider.sendAndConfirm(tx);
+}
+
+export async function tokenBalance(ctx: Ctx, tokenAccount: PublicKey): Promise<number> {
+  const account = await ctx.context.banksClient.getAccount(tokenAccount);
+  return Number(AccountLayout.decode(Buffer.from(account.data)).amount);
+}
@@ -0,0 +1,211 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  depositToTreasury,
+  createMarket,
+  openPosition,
+  placePosition,
+  positionPda,
+  resolveMarket,
+  claimWinnings,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+// Floating point reference for C(q) = b * ln(sum(e^(q_i / b)))
+function lmsrCost(shares: number[], b: number): number {
+  return b * Math.log(shares.reduce((sum, q) => sum + Math.exp(q / b), 0));
+}
+
+describe('lmsr markets', () => {
+  let ctx: Ctx;
+  let market: PublicKey;
+  let alice: Keypair;
+  let bob: Keypair;
+
+  const protocolFee = 2;
+  const b = sol(1).toNumber();
+
+  function fundMarket(liquidity: anchor.BN, admin: Keypair = ctx.admin) {
+    return ctx.program.methods
+      .fundMarket(liquidity)
+      .accountsPartial({
+        admin: admin.publicKey,
+        market,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  function buyShares(user: Keypair, outcome: number, amount: number, maxCost: number) {
+    return ctx.program.methods
+      .buyShares(outcome, new anchor.BN(amount), new anchor.BN(maxCost))
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        position: positionPda(ctx, market, user.publicKey),
+        fplUser: null,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function sellShares(user: Keypair, outcome: number, amount: number, minRefund: number) {
+    return ctx.program.methods
+      .sellShares(outcome, new anchor.BN(amount), new anchor.BN(minRefund))
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        position: positionPda(ctx, market, user.publicKey),
+        marketVault: pda(ctx.program, "market-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function prices(): Promise<number[]> {
+    const result = await ctx.program.methods.marketPrices().accountsPartial({ market }).view();
+    return result.map((price: anchor.BN) => price.toNumber() / 1e12);
+  }
+
+  async function shares(): Promise<number[]> {
+    const account = await ctx.program.account.market.fetch(market);
+    return account.shares.map((q) => q.toNumber());
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx, { protocolFee });
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+    await depositToTreasury(ctx, sol(10));
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+
+    market = await createMarket(ctx, { mechanism: { lmsr: {} } });
+    await openPosition(ctx, market, alice);
+    await openPosition(ctx, market, bob);
+  });
+
+  // LMSR markets can't be traded until the treasury funds them
+  it('Rejects trading an unfunded market', async () => {
+    await expectError(placePosition(ctx, market, alice, 0, sol(1)), 'WrongMarketMechanism');
+    await expectError(buyShares(alice, 0, b, b), 'MarketNotFunded');
+    await expectError(prices(), 'MarketNotFunded');
+  });
+
+  // The treasury puts up the maker's worst case loss, b * ln(n)
+  it('Funds the market from the treasury', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(fundMarket(sol(1), outsider), 'UnauthorizedAccess');
+    await expectError(fundMarket(sol(0)), 'InvalidMarketParameter');
+
+    const treasuryVaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    await fundMarket(sol(1));
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.liquidity.toNumber()).to.equal(b);
+    expect(account.subsidy.toNumber()).to.be.closeTo(b * Math.LN2, 10);
+    expect(account.subsidy.toNumber()).to.be.at.least(Math.ceil(b * Math.LN2));
+    expect(treasuryVaultBefore - (await balance(ctx.context, pda(ctx.program, "treasury-vault")))).to.equal(
+      account.subsidy.toNumber()
+    );
+
+    await warp(ctx.context, 0);
+    await expectError(fundMarket(sol(1)), 'MarketAlreadyFunded');
+
+    const [yes, no] = await prices();
+    expect(yes).to.be.closeTo(0.5, 1e-6);
+    expect(no).to.be.closeTo(0.5, 1e-6);
+  });
+
+  // Buying moves the price, the protocol fee is charged on top of the cost
+  it('Buys shares at the LMSR cost', async () => {
+    const expectedCost = lmsrCost([b, 0], b) - lmsrCost([0, 0], b);
+    await expectError(buyShares(alice, 0, b, Math.floor(expectedCost)), 'SlippageExceeded');
+    await expectError(buyShares(alice, 2, b, 2 * b), 'InvalidOutcome');
+
+    const aliceBefore = await balance(ctx.context, alice.publicKey);
+    const treasuryVaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    await buyShares(alice, 0, b, 2 * b);
+
+    const position = await ctx.program.account.position.fetch(positionPda(ctx, market, alice.publicKey));
+    const cost = position.amounts[0].toNumber();
+    const fee = Math.floor((cost * protocolFee) / 100);
+    expect(cost).to.be.closeTo(expectedCost, 10);
+    expect(cost).to.be.greaterThan(expectedCost);
+    expect(position.shares[0].toNumber()).to.equal(b);
+
+    expect(aliceBefore - (await balance(ctx.context, alice.publicKey))).to.equal(cost + fee);
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - treasuryVaultBefore).to.equal(fee);
+
+    const [yes, no] = await prices();
+    expect(yes).to.be.closeTo(Math.E / (Math.E + 1), 1e-6);
+    expect(yes + no).to.be.closeTo(1, 1e-6);
+  });
+
+  // Selling returns the cost difference, rounded in the market's favour
+  it('Sells shares back to the market', async () => {
+    const expectedRefund = lmsrCost([b, 0], b) - lmsrCost([b / 2, 0], b);
+    await expectError(sellShares(alice, 0, 2 * b, 0), 'InsufficientShares');
+    await expectError(sellShares(alice, 0, b / 2, Math.ceil(expectedRefund)), 'SlippageExceeded');
+
+    const before = await balance(ctx.context, alice.publicKey);
+    await sellShares(alice, 0, b / 2, 0);
+
+    const refund = (await balance(ctx.context, alice.publicKey)) - before;
+    expect(refund).to.be.closeTo(expectedRefund, 10);
+    expect(refund).to.be.lessThan(expectedRefund);
+    expect(await shares()).to.deep.equal([b / 2, 0]);
+  });
+
+  // After resolution winning shares pay one lamport each and the rest goes back to the treasury
+  it('Settles winning shares and returns the surplus', async () => {
+    await buyShares(bob, 1, 2 * b, 4 * b);
+
+    await warp(ctx.context, DAY);
+    await expectError(buyShares(bob, 1, b, 2 * b), 'MarketClosed');
+
+    const before = await ctx.program.account.market.fetch(market);
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+    await resolveMarket(ctx, market, 0);
+
+    // Everything beyond what the winning shares are owed returns to the treasury
+    const surplus = before.totalPool.toNumber() - b / 2;
+    expect((await fetchTreasury(ctx)).totalFees.toNumber() - feesBefore).to.equal(surplus);
+
+    const aliceBefore = await balance(ctx.context, alice.publicKey);
+    await claimWinnings(ctx, market, alice);
+    expect((await balance(ctx.context, alice.publicKey)) - aliceBefore).to.equal(b / 2);
+
+    await expectError(claimWinnings(ctx, market, bob), 'NoWinnings');
+  });
+});
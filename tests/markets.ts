@@ -0,0 +1,164 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  fixturePda,
+  newUser,
+  createMarket,
+  marketParams,
+  marketPda,
+  openPosition,
+  placePosition,
+  proposeResolution,
+  finalizeResolution,
+  claimWinnings,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('binary markets', () => {
+  let ctx: Ctx;
+  let market: PublicKey;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+
+  const protocolFee = 10;
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx, { protocolFee });
+    await initProtocol(ctx);
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+    carol = await newUser(ctx.context);
+  });
+
+  // Bonds, windows and fee caps are validated up front
+  it('Rejects invalid market configs', async () => {
+    await expectError(initMarketConfig(ctx, { minProposalBond: sol(0) }), 'InvalidMarketParameter');
+    await expectError(initMarketConfig(ctx, { autoVoidDelay: new anchor.BN(0) }), 'InvalidMarketParameter');
+    await expectError(initMarketConfig(ctx, { maxCreatorFeeBps: 1_001 }), 'InvalidMarketParameter');
+
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+  });
+
+  // Only the admin creates protocol markets, and they need a sensible shape
+  it('Creates a market', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(
+      ctx.program.methods
+        .createMarket((await marketParams(ctx)) as any)
+        .accountsPartial({
+          admin: outsider.publicKey,
+          marketConfig: pda(ctx.program, "market-config"),
+          market: marketPda(ctx, 0),
+          marketVault: pda(ctx.program, "market-vault", marketPda(ctx, 0)),
+          globalState: pda(ctx.program, "fpl-global"),
+          fixture: fixturePda(ctx, 1),
+          protocolState: pda(ctx.program, "protocol-state"),
+        })
+        .signers([outsider])
+        .rpc(),
+      'UnauthorizedAccess'
+    );
+
+    await expectError(createMarket(ctx, { question: "" }), 'InvalidMarketParameter');
+    await expectError(createMarket(ctx, { outcomes: ["Yes"] }), 'InvalidMarketParameter');
+    await expectError(createMarket(ctx, { outcomes: ["Yes", ""] }), 'InvalidMarketParameter');
+    await expectError(createMarket(ctx, { closeTime: new anchor.BN(0) }), 'InvalidMarketParameter');
+
+    // Markets need a published fixture and close by its kickoff
+    const { kickoff } = await ctx.program.account.fixture.fetch(fixturePda(ctx, 1));
+    await expectError(createMarket(ctx, { fixtureId: new anchor.BN(9) }), 'AccountNotInitialized');
+    await expectError(createMarket(ctx, { closeTime: kickoff.addn(1) }), 'InvalidMarketParameter');
+
+    market = await createMarket(ctx);
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.outcomes).to.deep.equal(["Yes", "No"]);
+    expect(account.status).to.deep.equal({ open: {} });
+    expect(account.totalPool.toNumber()).to.equal(0);
+  });
+
+  // Collateral goes into the market vault and onto the outcome pool
+  it('Places positions', async () => {
+    for (const user of [alice, bob, carol]) {
+      await openPosition(ctx, market, user);
+    }
+
+    await expectError(placePosition(ctx, market, alice, 2, sol(1)), 'InvalidOutcome');
+    await expectError(placePosition(ctx, market, alice, 0, sol(0)), 'InvalidPositionAmount');
+
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "market-vault", market));
+    await placePosition(ctx, market, alice, 0, sol(3));
+    await placePosition(ctx, market, bob, 1, sol(1));
+    await placePosition(ctx, market, carol, 1, sol(1));
+
+    expect((await balance(ctx.context, pda(ctx.program, "market-vault", market))) - vaultBefore).to.equal(sol(5).toNumber());
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.outcomePools.map((pool) => pool.toString())).to.deep.equal([sol(3).toString(), sol(2).toString()]);
+    expect(account.totalPool.toString()).to.equal(sol(5).toString());
+
+    const position = await ctx.program.account.position.fetch(pda(ctx.program, "position", market, alice.publicKey));
+    expect(position.amounts[0].toString()).to.equal(sol(3).toString());
+  });
+
+  // Outcomes can only be proposed once the market has closed, and then it takes no more positions
+  it('Closes the market at its close time', async () => {
+    await expectError(proposeResolution(ctx, market, 0), 'MarketNotClosed');
+
+    await warp(ctx.context, DAY);
+    await expectError(placePosition(ctx, market, alice, 0, sol(1)), 'MarketClosed');
+  });
+
+  // Resolution settles the protocol fee on the losing pool to the treasury
+  it('Resolves the market and takes the protocol fee', async () => {
+    await expectError(proposeResolution(ctx, market, 2), 'InvalidOutcome');
+    await proposeResolution(ctx, market, 0);
+    await expectError(finalizeResolution(ctx, market), 'DisputeWindowOpen');
+
+    await warp(ctx.context, DAY + 1);
+    const treasuryVaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    await finalizeResolution(ctx, market);
+
+    const fee = sol(0.2).toNumber();
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.status).to.deep.equal({ resolved: {} });
+    expect(account.winningOutcome).to.equal(0);
+    expect(account.feeAmount.toNumber()).to.equal(fee);
+    expect(account.distributable.toString()).to.equal(sol(1.8).toString());
+
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - treasuryVaultBefore).to.equal(fee);
+    expect((await fetchTreasury(ctx)).totalFees.toNumber()).to.equal(fee);
+  });
+
+  // Winners get their stake back plus the losing pool net of the fee
+  it('Pays out winners once', async () => {
+    const before = await balance(ctx.context, alice.publicKey);
+    await claimWinnings(ctx, market, alice);
+    expect((await balance(ctx.context, alice.publicKey)) - before).to.equal(sol(4.8).toNumber());
+
+    await warp(ctx.context, 0);
+    await expectError(claimWinnings(ctx, market, alice), 'AlreadyClaimed');
+    await expectError(claimWinnings(ctx, market, bob), 'NoWinnings');
+  });
+});
@@ -0,0 +1,298 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  newUser,
+  depositToTreasury,
+  withdrawFromTreasury,
+  fetchTreasury,
+  expectError,
+  balance,
+  now,
+  pda,
+  u64,
+  warp,
+  sol,
+} from './helpers';
+
+describe('treasury multisig', () => {
+  let ctx: Ctx;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+  let multisig: PublicKey;
+
+  function initMultisig(signers: PublicKey[], threshold: number) {
+    return ctx.program.methods
+      .initializeMultisig(signers, threshold)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        multisig: pda(ctx.program, "treasury-multisig"),
+      })
+      .rpc();
+  }
+
+  function proposalPda(id: number) {
+    return pda(ctx.program, "proposal", multisig, u64(id));
+  }
+
+  async function propose(proposer: Keypair, kind: object, expiresIn = 86400): Promise<number> {
+    const { proposalCount } = await ctx.program.account.treasuryMultisig.fetch(multisig);
+    const id = proposalCount.toNumber();
+
+    await ctx.program.methods
+      .createProposal(kind as any, new anchor.BN(expiresIn))
+      .accountsPartial({
+        proposer: proposer.publicKey,
+        multisig,
+        proposal: proposalPda(id),
+      })
+      .signers([proposer])
+      .rpc();
+
+    return id;
+  }
+
+  function withdrawal(amount: anchor.BN, recipient: PublicKey) {
+    return { withdraw: { amount, recipient, mint: null, memo: "grant" } };
+  }
+
+  function approve(signer: Keypair, id: number) {
+    return ctx.program.methods
+      .approveProposal()
+      .accountsPartial({ signer: signer.publicKey, multisig, proposal: proposalPda(id) })
+      .signers([signer])
+      .rpc();
+  }
+
+  function cancel(signer: Keypair, id: number) {
+    return ctx.program.methods
+      .cancelProposal()
+      .accountsPartial({ signer: signer.publicKey, multisig, proposal: proposalPda(id) })
+      .signers([signer])
+      .rpc();
+  }
+
+  function execute(executor: Keypair, id: number, recipient: PublicKey) {
+    return ctx.program.methods
+      .executeProposal()
+      .accountsPartial({
+        executor: executor.publicKey,
+        multisig,
+        proposal: proposalPda(id),
+        treasury: pda(ctx.program, "treasury"),
+        timelock: pda(ctx.program, "timelock"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        recipient,
+        treasuryMint: null,
+        tokenVault: null,
+        recipientTokenAccount: null,
+        tokenProgram: null,
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([executor])
+      .rpc();
+  }
+
+  function createBudget(authority: Keypair, name: string, allocation: anchor.BN, id: number | null) {
+    return ctx.program.methods
+      .createBudget(name, allocation)
+      .accountsPartial({
+        authority: authority.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        timelock: pda(ctx.program, "timelock"),
+        budget: pda(ctx.program, "budget", name),
+        pendingAction: null,
+        multisig: id === null ? null : multisig,
+        proposal: id === null ? null : proposalPda(id),
+      })
+      .signers(authority === ctx.admin ? [] : [authority])
+      .rpc();
+  }
+
+  function createStream(authority: Keypair, budget: PublicKey, kind: any, id: number) {
+    const { recipient, amount, startTime, duration } = kind.createStream;
+    return ctx.program.methods
+      .createStream(recipient, amount, startTime, duration)
+      .accountsPartial({
+        authority: authority.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        budget,
+        stream: pda(ctx.program, "stream", budget, u64(0)),
+        multisig,
+        proposal: proposalPda(id),
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initTimelock(ctx, 86400, sol(10));
+    await depositToTreasury(ctx, sol(20));
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+    carol = await newUser(ctx.context);
+    multisig = pda(ctx.program, "treasury-multisig");
+  });
+
+  // Thresholds have to be reachable and signers unique
+  it('Rejects invalid signer sets', async () => {
+    await expectError(initMultisig([alice.publicKey, bob.publicKey], 3), 'InvalidMultisigConfig');
+    await expectError(initMultisig([alice.publicKey, alice.publicKey], 1), 'InvalidMultisigConfig');
+    await expectError(initMultisig([alice.publicKey], 0), 'InvalidMultisigConfig');
+  });
+
+  // Once the multisig is set up the admin can't withdraw alone
+  it('Disables single-admin withdrawals', async () => {
+    await initMultisig([alice.publicKey, bob.publicKey, carol.publicKey], 2);
+    expect((await fetchTreasury(ctx)).multisigEnabled).to.be.true;
+
+    await expectError(withdrawFromTreasury(ctx, sol(1), ctx.admin.publicKey), 'MultisigRequired');
+  });
+
+  // Only signers can propose
+  it('Rejects proposals from non-signers', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(propose(outsider, withdrawal(sol(1), outsider.publicKey)), 'NotMultisigSigner');
+  });
+
+  // M-of-N approvals release the withdrawal to the proposed recipient
+  it('Executes a withdrawal once the threshold is met', async () => {
+    const recipient = Keypair.generate();
+    const id = await propose(alice, withdrawal(sol(2), recipient.publicKey));
+
+    await expectError(execute(alice, id, recipient.publicKey), 'ThresholdNotMet');
+    await expectError(approve(alice, id), 'AlreadyApproved');
+
+    await approve(bob, id);
+    await expectError(execute(alice, id, bob.publicKey), 'UnauthorizedAccess');
+
+    await execute(carol, id, recipient.publicKey);
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(sol(2).toNumber());
+
+    const proposal = await ctx.program.account.multisigProposal.fetch(proposalPda(id));
+    expect(proposal.executed).to.be.true;
+    expect((await fetchTreasury(ctx)).totalFees.toString()).to.equal(sol(18).toString());
+
+    await warp(ctx.context, 0);
+    await expectError(execute(carol, id, recipient.publicKey), 'ProposalAlreadyExecuted');
+  });
+
+  // Proposals can't be approved after they expire
+  it('Rejects approvals after expiry', async () => {
+    const id = await propose(alice, withdrawal(sol(1), alice.publicKey), 3600);
+    await warp(ctx.context, 7200);
+
+    await expectError(approve(bob, id), 'ProposalExpired');
+    await expectError(execute(alice, id, alice.publicKey), 'ProposalExpired');
+  });
+
+  // Approved withdrawals over the timelock threshold still wait out the delay
+  it('Holds large withdrawals for the timelock delay', async () => {
+    const recipient = Keypair.generate();
+    const id = await propose(alice, withdrawal(sol(12), recipient.publicKey));
+    await approve(bob, id);
+
+    // The first execution only starts the clock
+    await execute(alice, id, recipient.publicKey);
+    const proposal = await ctx.program.account.multisigProposal.fetch(proposalPda(id));
+    expect(proposal.executed).to.be.false;
+    expect(proposal.eta.toNumber()).to.be.greaterThan(0);
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(0);
+
+    await warp(ctx.context, 0);
+    await expectError(execute(alice, id, recipient.publicKey), 'TimelockNotReady');
+
+    await warp(ctx.context, 86400);
+    await execute(alice, id, recipient.publicKey);
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(sol(12).toNumber());
+  });
+
+  // A queued withdrawal can still be stopped by the threshold before its eta
+  it('Cancels a queued withdrawal once the threshold votes to', async () => {
+    await depositToTreasury(ctx, sol(10));
+    const recipient = Keypair.generate();
+    const id = await propose(alice, withdrawal(sol(10), recipient.publicKey));
+    await approve(bob, id);
+    await execute(alice, id, recipient.publicKey);
+
+    const outsider = await newUser(ctx.context);
+    await expectError(cancel(outsider, id), 'NotMultisigSigner');
+
+    // One vote isn't enough under a 2-of-3
+    await cancel(carol, id);
+    await expectError(cancel(carol, id), 'AlreadyApproved');
+    expect((await ctx.program.account.multisigProposal.fetch(proposalPda(id))).cancelled).to.be.false;
+
+    await cancel(bob, id);
+    expect((await ctx.program.account.multisigProposal.fetch(proposalPda(id))).cancelled).to.be.true;
+
+    await warp(ctx.context, 86400);
+    await expectError(execute(alice, id, recipient.publicKey), 'ProposalCancelled');
+    await expectError(approve(carol, id), 'ProposalCancelled');
+    await expectError(cancel(alice, id), 'ProposalCancelled');
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(0);
+  });
+
+  // Budgets and streams need their own approved proposals once multisig is on
+  it('Creates budgets and streams through proposals', async () => {
+    await expectError(createBudget(ctx.admin, "grants", sol(3), null), 'MultisigRequired');
+
+    const id = await propose(alice, { createBudget: { name: "grants", allocation: sol(3) } });
+    await expectError(createBudget(alice, "grants", sol(3), id), 'ThresholdNotMet');
+    await approve(bob, id);
+
+    await expectError(createBudget(alice, "grants", sol(2), id), 'ApprovalMismatch');
+    await createBudget(alice, "grants", sol(3), id);
+    expect((await ctx.program.account.multisigProposal.fetch(proposalPda(id))).executed).to.be.true;
+    expect((await fetchTreasury(ctx)).budgeted.toString()).to.equal(sol(3).toString());
+
+    const budget = pda(ctx.program, "budget", "grants");
+    const kind = {
+      createStream: {
+        budget,
+        recipient: carol.publicKey,
+        amount: sol(3),
+        startTime: new anchor.BN(await now(ctx.context)),
+        duration: new anchor.BN(86400),
+      },
+    };
+    const stream = await propose(alice, kind);
+    await approve(carol, stream);
+
+    await expectError(createStream(bob, budget, { createStream: { ...kind.createStream, recipient: bob.publicKey } }, stream), 'ApprovalMismatch');
+    await createStream(bob, budget, kind, stream);
+    expect((await ctx.program.account.budget.fetch(budget)).committed.toString()).to.equal(sol(3).toString());
+  });
+
+  // Rotating signers invalidates everything proposed under the old set
+  it('Changes signers and invalidates outstanding proposals', async () => {
+    const pending = await propose(alice, withdrawal(sol(1), alice.publicKey));
+    const change = await propose(alice, { changeSigners: { signers: [alice.publicKey, bob.publicKey], threshold: 2 } });
+
+    await approve(bob, change);
+    await execute(bob, change, bob.publicKey);
+
+    const updated = await ctx.program.account.treasuryMultisig.fetch(multisig);
+    expect(updated.signers.map((signer) => signer.toString())).to.deep.equal([
+      alice.publicKey.toString(),
+      bob.publicKey.toString(),
+    ]);
+    expect(updated.signerSetSeqno).to.equal(1);
+
+    await expectError(approve(bob, pending), 'StaleProposal');
+    await expectError(propose(carol, withdrawal(sol(1), carol.publicKey)), 'NotMultisigSigner');
+  });
+});
@@ -0,0 +1,184 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  createMarket,
+  resolveMarket,
+  createTokenAccount,
+  tokenBalance,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('outcome tokens', () => {
+  let ctx: Ctx;
+  let market: PublicKey;
+  let alice: Keypair;
+  let bob: Keypair;
+  let mints: PublicKey[];
+
+  // Token accounts per user, in outcome order
+  const accounts = new Map<string, PublicKey[]>();
+
+  function outcomeMintPda(market: PublicKey, outcome: number): PublicKey {
+    return pda(ctx.program, "outcome-mint", market, Buffer.from([outcome]));
+  }
+
+  function initOutcomeMint(market: PublicKey, outcome: number, admin: Keypair = ctx.admin) {
+    return ctx.program.methods
+      .initializeOutcomeMint(outcome)
+      .accountsPartial({
+        admin: admin.publicKey,
+        marketConfig: pda(ctx.program, "market-config"),
+        market,
+        outcomeMint: outcomeMintPda(market, outcome),
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  // (mint, token account) pairs in outcome order
+  function outcomeAccounts(user: Keypair, order = [0, 1]) {
+    const tokens = accounts.get(user.publicKey.toString()) ?? [];
+    return order.flatMap((outcome) => [
+      { pubkey: mints[outcome], isSigner: false, isWritable: true },
+      { pubkey: tokens[outcome], isSigner: false, isWritable: true },
+    ]);
+  }
+
+  function completeSet(method: 'mintCompleteSet' | 'redeemCompleteSet', user: Keypair, amount: anchor.BN, remaining = outcomeAccounts(user)) {
+    return ctx.program.methods[method](amount)
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        fplUser: null,
+        marketVault: pda(ctx.program, "market-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .remainingAccounts(remaining)
+      .signers([user])
+      .rpc();
+  }
+
+  function redeemWinning(user: Keypair, outcome: number, amount: anchor.BN) {
+    return ctx.program.methods
+      .redeemWinning(amount)
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        winningMint: mints[outcome],
+        userTokenAccount: accounts.get(user.publicKey.toString())[outcome],
+        marketVault: pda(ctx.program, "market-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function tokens(user: Keypair): Promise<number[]> {
+    return Promise.all(accounts.get(user.publicKey.toString()).map((account) => tokenBalance(ctx, account)));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+
+    market = await createMarket(ctx, { mechanism: { outcomeTokens: {} } });
+    mints = [outcomeMintPda(market, 0), outcomeMintPda(market, 1)];
+  });
+
+  // Each outcome gets its own mint, created by the admin in outcome order
+  it('Creates the outcome mints', async () => {
+    await expectError(completeSet('mintCompleteSet', alice, sol(1), []), 'OutcomeMintsMissing');
+
+    const outsider = await newUser(ctx.context);
+    await expectError(initOutcomeMint(market, 0, outsider), 'UnauthorizedAccess');
+    await expectError(initOutcomeMint(market, 1), 'InvalidOutcome');
+
+    const parimutuel = await createMarket(ctx);
+    await expectError(initOutcomeMint(parimutuel, 0), 'WrongMarketMechanism');
+
+    await initOutcomeMint(market, 0);
+    await initOutcomeMint(market, 1);
+    await expectError(initOutcomeMint(market, 2), 'InvalidOutcome');
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.outcomeMints.map((mint) => mint.toString())).to.deep.equal(mints.map((mint) => mint.toString()));
+
+    for (const user of [alice, bob]) {
+      accounts.set(user.publicKey.toString(), await Promise.all(mints.map((mint) => createTokenAccount(ctx, mint, user.publicKey))));
+    }
+  });
+
+  // A complete set is one token of every outcome for one lamport of collateral
+  it('Mints complete sets against collateral', async () => {
+    await expectError(completeSet('mintCompleteSet', alice, sol(1), outcomeAccounts(alice).slice(2)), 'TokenAccountsRequired');
+    await expectError(completeSet('mintCompleteSet', alice, sol(1), outcomeAccounts(alice, [1, 0])), 'InvalidOutcome');
+    await expectError(completeSet('mintCompleteSet', alice, sol(0)), 'InvalidPositionAmount');
+
+    const aliceBefore = await balance(ctx.context, alice.publicKey);
+    await completeSet('mintCompleteSet', alice, sol(2));
+    await completeSet('mintCompleteSet', bob, sol(1));
+
+    expect(aliceBefore - (await balance(ctx.context, alice.publicKey))).to.equal(sol(2).toNumber());
+    expect(await tokens(alice)).to.deep.equal([sol(2).toNumber(), sol(2).toNumber()]);
+    expect((await ctx.program.account.market.fetch(market)).totalPool.toString()).to.equal(sol(3).toString());
+  });
+
+  // Before resolution a complete set can be burned for its collateral
+  it('Redeems complete sets before resolution', async () => {
+    await expectError(completeSet('redeemCompleteSet', bob, sol(4)), 'InvalidPositionAmount');
+    await expectError(redeemWinning(bob, 0, sol(1)), 'MarketNotResolved');
+
+    const before = await balance(ctx.context, bob.publicKey);
+    await completeSet('redeemCompleteSet', bob, sol(0.5));
+
+    expect((await balance(ctx.context, bob.publicKey)) - before).to.equal(sol(0.5).toNumber());
+    expect(await tokens(bob)).to.deep.equal([sol(0.5).toNumber(), sol(0.5).toNumber()]);
+    expect((await ctx.program.account.market.fetch(market)).totalPool.toString()).to.equal(sol(2.5).toString());
+  });
+
+  // After resolution only the winning token redeems, one lamport per token
+  it('Redeems winning tokens after resolution', async () => {
+    await warp(ctx.context, DAY);
+    await resolveMarket(ctx, market, 0);
+
+    await expectError(completeSet('redeemCompleteSet', alice, sol(1)), 'MarketAlreadyResolved');
+    await expectError(redeemWinning(alice, 1, sol(1)), 'InvalidOutcome');
+
+    const aliceBefore = await balance(ctx.context, alice.publicKey);
+    await redeemWinning(alice, 0, sol(2));
+    expect((await balance(ctx.context, alice.publicKey)) - aliceBefore).to.equal(sol(2).toNumber());
+    expect(await tokens(alice)).to.deep.equal([0, sol(2).toNumber()]);
+
+    const bobBefore = await balance(ctx.context, bob.publicKey);
+    await redeemWinning(bob, 0, sol(0.5));
+    expect((await balance(ctx.context, bob.publicKey)) - bobBefore).to.equal(sol(0.5).toNumber());
+
+    // Every winning token is paid, the vault is back to its rent reserve
+    const rent = await ctx.context.banksClient.getRent();
+    expect((await ctx.program.account.market.fetch(market)).totalPool.toNumber()).to.equal(0);
+    expect(await balance(ctx.context, pda(ctx.program, "market-vault", market))).to.equal(Number(rent.minimumBalance(BigInt(0))));
+  });
+});
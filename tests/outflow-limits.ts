@@ -0,0 +1,114 @@
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initRewards,
+  initStakeConfig,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  depositToTreasury,
+  withdrawFromTreasury,
+  queueAction,
+  executeAction,
+  fetchTreasury,
+  expectError,
+  balance,
+  warp,
+  sol,
+} from './helpers';
+
+describe('treasury outflow limits', () => {
+  let ctx: Ctx;
+  let recipient: Keypair;
+
+  function queueTreasuryUpdate(update: Record<string, unknown>) {
+    return queueAction(ctx, {
+      updateTreasuryConfig: {
+        update: {
+          newProtocolFee: null,
+          newReservePercentage: null,
+          newRewardsShare: null,
+          newOutflowCap: null,
+          newOutflowCapPercentage: null,
+          newOutflowWindow: null,
+          ...update,
+        },
+      },
+    });
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx, {}, sol(0));
+    recipient = Keypair.generate();
+  });
+
+  // Percentages over 100 and empty windows are rejected up front
+  it('Rejects invalid outflow limits', async () => {
+    await expectError(initTreasury(ctx, { outflowCapPercentage: 101 }), 'InvalidTreasuryParameter');
+    await expectError(initTreasury(ctx, { outflowWindow: sol(0) }), 'InvalidTreasuryParameter');
+
+    await initTreasury(ctx, { outflowCap: sol(5) });
+    await initProtocol(ctx);
+    await initTimelock(ctx);
+    await depositToTreasury(ctx, sol(20));
+  });
+
+  // Repeated withdrawals add up against the window cap
+  it('Caps the total withdrawn within a window', async () => {
+    await withdrawFromTreasury(ctx, sol(3), recipient.publicKey);
+    await expectError(withdrawFromTreasury(ctx, sol(3), recipient.publicKey), 'OutflowLimitExceeded');
+
+    await withdrawFromTreasury(ctx, sol(2), recipient.publicKey);
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(sol(5).toNumber());
+  });
+
+  // The window rolls, it doesn't reset partway through
+  it('Frees capacity once outflows leave the window', async () => {
+    await warp(ctx.context, DAY / 2);
+    await expectError(withdrawFromTreasury(ctx, sol(1), recipient.publicKey), 'OutflowLimitExceeded');
+
+    await warp(ctx.context, 2 * DAY);
+    await withdrawFromTreasury(ctx, sol(3), recipient.publicKey);
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(sol(12).toString());
+  });
+
+  // Limit changes go through the timelock and are validated when queued
+  it('Updates the limits through the timelock', async () => {
+    await expectError(queueTreasuryUpdate({ newOutflowCapPercentage: 101 }), 'InvalidTreasuryParameter');
+    await expectError(queueTreasuryUpdate({ newOutflowWindow: sol(0) }), 'InvalidTreasuryParameter');
+
+    const id = await queueTreasuryUpdate({ newOutflowCap: sol(0), newOutflowCapPercentage: 10 });
+    await warp(ctx.context, 2 * DAY);
+    await executeAction(ctx, id);
+
+    const { outflowLimit } = await fetchTreasury(ctx);
+    expect(outflowLimit.cap.toNumber()).to.equal(0);
+    expect(outflowLimit.capPercentage).to.equal(10);
+  });
+
+  // The percentage cap is measured against the vault balance before the window's outflows
+  it('Caps the share of the vault withdrawn within a window', async () => {
+    // Roughly 12 SOL left in the vault
+    await expectError(withdrawFromTreasury(ctx, sol(1.3), recipient.publicKey), 'OutflowLimitExceeded');
+
+    await withdrawFromTreasury(ctx, sol(1.2), recipient.publicKey);
+    await expectError(withdrawFromTreasury(ctx, sol(0.1), recipient.publicKey), 'OutflowLimitExceeded');
+  });
+
+  // Timelocked withdrawals are outflows too
+  it('Applies the limits to queued withdrawals', async () => {
+    const id = await queueAction(ctx, { withdraw: { amount: sol(2), recipient: recipient.publicKey, mint: null } });
+    await warp(ctx.context, 2 * DAY);
+
+    await expectError(executeAction(ctx, id, { recipient: recipient.publicKey }), 'OutflowLimitExceeded');
+  });
+});
@@ -0,0 +1,137 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  createMarket,
+  openPosition,
+  placePosition,
+  resolveMarket,
+  claimWinnings,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('parimutuel settlement', () => {
+  let ctx: Ctx;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+  let dave: Keypair;
+
+  const protocolFee = 10;
+  const outcomes = ["Home", "Draw", "Away"];
+
+  async function payout(market: PublicKey, user: Keypair): Promise<number> {
+    const before = await balance(ctx.context, user.publicKey);
+    await claimWinnings(ctx, market, user);
+    return (await balance(ctx.context, user.publicKey)) - before;
+  }
+
+  async function rentReserve(): Promise<number> {
+    const rent = await ctx.context.banksClient.getRent();
+    return Number(rent.minimumBalance(BigInt(0)));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx, { protocolFee });
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+    carol = await newUser(ctx.context);
+    dave = await newUser(ctx.context);
+  });
+
+  // Winners split the losing pools pro-rata, the rounding dust goes to the treasury
+  it('Shares the losing pools pro-rata among winners', async () => {
+    const market = await createMarket(ctx, { outcomes });
+    for (const user of [alice, bob, carol, dave]) {
+      await openPosition(ctx, market, user);
+    }
+
+    await placePosition(ctx, market, alice, 0, sol(1));
+    await placePosition(ctx, market, bob, 0, sol(2));
+    await placePosition(ctx, market, carol, 1, sol(1));
+    await placePosition(ctx, market, dave, 2, new anchor.BN(1));
+
+    await warp(ctx.context, DAY);
+    await resolveMarket(ctx, market, 0);
+
+    // 1 SOL + 1 lamport lost, 10% of it to the treasury
+    const losingPool = sol(1).toNumber() + 1;
+    const fee = Math.floor((losingPool * protocolFee) / 100);
+    const distributable = losingPool - fee;
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.feeAmount.toNumber()).to.equal(fee);
+    expect(account.distributable.toNumber()).to.equal(distributable);
+
+    const aliceShare = Math.floor(distributable / 3);
+    const bobShare = Math.floor((2 * distributable) / 3);
+    expect(await payout(market, alice)).to.equal(sol(1).toNumber() + aliceShare);
+    expect(await payout(market, bob)).to.equal(sol(2).toNumber() + bobShare);
+
+    // The last winner's claim settles the remainder
+    const remainder = distributable - aliceShare - bobShare;
+    expect(remainder).to.be.greaterThan(0);
+    expect((await fetchTreasury(ctx)).totalFees.toNumber()).to.equal(fee + remainder);
+    expect(await balance(ctx.context, pda(ctx.program, "market-vault", market))).to.equal(await rentReserve());
+
+    await expectError(claimWinnings(ctx, market, carol), 'NoWinnings');
+    await expectError(claimWinnings(ctx, market, dave), 'NoWinnings');
+  });
+
+  // Nobody picked the winner, so every position comes back at cost with no fee
+  it('Refunds everyone when nobody picked the winning outcome', async () => {
+    const market = await createMarket(ctx, { outcomes });
+    for (const user of [alice, bob]) {
+      await openPosition(ctx, market, user);
+    }
+
+    await placePosition(ctx, market, alice, 0, sol(2));
+    await placePosition(ctx, market, bob, 1, sol(3));
+
+    await warp(ctx.context, DAY);
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+    await resolveMarket(ctx, market, 2);
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.feeAmount.toNumber()).to.equal(0);
+    expect(account.distributable.toNumber()).to.equal(0);
+
+    expect(await payout(market, alice)).to.equal(sol(2).toNumber());
+    expect(await payout(market, bob)).to.equal(sol(3).toNumber());
+    expect((await fetchTreasury(ctx)).totalFees.toNumber()).to.equal(feesBefore);
+
+    await warp(ctx.context, 0);
+    await expectError(claimWinnings(ctx, market, alice), 'AlreadyClaimed');
+  });
+
+  // Positions can't be claimed before the market is resolved
+  it('Rejects claims on an open market', async () => {
+    const market = await createMarket(ctx, { outcomes });
+    await openPosition(ctx, market, alice);
+    await placePosition(ctx, market, alice, 0, sol(1));
+
+    await expectError(claimWinnings(ctx, market, alice), 'MarketNotResolved');
+  });
+});
@@ -0,0 +1,120 @@
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initStakeConfig,
+  initRewards,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  newUser,
+  registerUser,
+  stake,
+  unstake,
+  claimRewards,
+  depositToTreasury,
+  withdrawFromTreasury,
+  queueAction,
+  executeAction,
+  expectError,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('emergency pause', () => {
+  let ctx: Ctx;
+  let guardian: Keypair;
+  let user: Keypair;
+  let stakeId: number;
+
+  const none = { staking: false, unstaking: false, claims: false, markets: false, treasury: false };
+
+  function pause(authority: Keypair, flags: Partial<typeof none>) {
+    return ctx.program.methods
+      .pause({ ...none, ...flags })
+      .accountsPartial({
+        authority: authority.publicKey,
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers(authority === ctx.admin ? [] : [authority])
+      .rpc();
+  }
+
+  async function paused() {
+    const state = await ctx.program.account.protocolState.fetch(pda(ctx.program, "protocol-state"));
+    return state.paused;
+  }
+
+  before(async () => {
+    ctx = await setup();
+    guardian = await newUser(ctx.context);
+
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx, guardian.publicKey);
+    await initTimelock(ctx);
+    await depositToTreasury(ctx, sol(5));
+
+    user = await newUser(ctx.context);
+    await registerUser(ctx, user);
+    stakeId = await stake(ctx, user, sol(10));
+  });
+
+  // Only the guardian or admin can pause, and a pause has to cover something
+  it('Rejects pauses from anyone else', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(pause(outsider, { staking: true }), 'UnauthorizedAccess');
+    await expectError(pause(guardian, {}), 'InvalidPauseFlags');
+  });
+
+  // The guardian stops instruction groups instantly
+  it('Pauses instruction groups', async () => {
+    await pause(guardian, { staking: true, claims: true });
+    await pause(ctx.admin, { unstaking: true, treasury: true, markets: true });
+
+    expect(await paused()).to.deep.equal({ staking: true, unstaking: true, claims: true, markets: true, treasury: true });
+
+    await warp(ctx.context, 10 * DAY);
+    await expectError(stake(ctx, user, sol(1)), 'ProtocolPaused');
+    await expectError(claimRewards(ctx, user, stakeId), 'ProtocolPaused');
+    await expectError(unstake(ctx, user, stakeId), 'ProtocolPaused');
+    await expectError(withdrawFromTreasury(ctx, sol(1), user.publicKey), 'ProtocolPaused');
+  });
+
+  // Unpausing waits out the timelock and only clears the queued flags
+  it('Unpauses through the timelock', async () => {
+    await expectError(queueAction(ctx, { unpause: { flags: none } }), 'InvalidPauseFlags');
+
+    const id = await queueAction(ctx, {
+      unpause: { flags: { staking: true, unstaking: true, claims: true, markets: false, treasury: true } },
+    });
+    await expectError(executeAction(ctx, id), 'TimelockNotReady');
+
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, id);
+    expect(await paused()).to.deep.equal({ ...none, markets: true });
+
+    await claimRewards(ctx, user, stakeId);
+    await stake(ctx, user, sol(1));
+    await unstake(ctx, user, stakeId);
+    await withdrawFromTreasury(ctx, sol(1), user.publicKey);
+  });
+
+  // The guardian key can be rotated through the timelock
+  it('Rotates the guardian', async () => {
+    const replacement = await newUser(ctx.context);
+    const id = await queueAction(ctx, { setGuardian: { guardian: replacement.publicKey } });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, id);
+
+    await expectError(pause(guardian, { staking: true }), 'UnauthorizedAccess');
+    await pause(replacement, { staking: true });
+    expect((await paused()).staking).to.be.true;
+  });
+});
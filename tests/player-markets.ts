@@ -0,0 +1,219 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  SEASON_START,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  createFixture,
+  newUser,
+  createMarket,
+  openPosition,
+  placePosition,
+  proposeResolution,
+  claimWinnings,
+  expectError,
+  balance,
+  pda,
+  u32,
+  warp,
+  sol,
+} from './helpers';
+
+describe('player markets', () => {
+  let ctx: Ctx;
+  let publisher: Keypair;
+  let alice: Keypair;
+  let bob: Keypair;
+  let pointsMarket: PublicKey;
+  let outscoresMarket: PublicKey;
+
+  const protocolFee = 10;
+  const salah = 10;
+  const haaland = 11;
+
+  const pointsAtLeast = { pointsAtLeast: { playerId: salah, gameweek: 1, threshold: 6 } };
+  const outscores = { outscores: { playerId: salah, otherPlayerId: haaland, gameweek: 1 } };
+
+  function playerPointsPda(playerId: number, gameweek: number): PublicKey {
+    return pda(ctx.program, "player-points", u32(playerId), Buffer.from([gameweek]));
+  }
+
+  function publishPoints(playerId: number, gameweek: number, points: number, signer: Keypair = publisher) {
+    return ctx.program.methods
+      .publishPlayerPoints(playerId, gameweek, points)
+      .accountsPartial({
+        publisher: signer.publicKey,
+        oracleConfig: pda(ctx.program, "oracle-config"),
+        globalState: pda(ctx.program, "fpl-global"),
+        playerPoints: playerPointsPda(playerId, gameweek),
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  function correctPoints(playerId: number, gameweek: number, points: number) {
+    return ctx.program.methods
+      .correctPlayerPoints(points)
+      .accountsPartial({
+        publisher: publisher.publicKey,
+        oracleConfig: pda(ctx.program, "oracle-config"),
+        globalState: pda(ctx.program, "fpl-global"),
+        playerPoints: playerPointsPda(playerId, gameweek),
+      })
+      .signers([publisher])
+      .rpc();
+  }
+
+  function finalizeGameweek(gameweek: number) {
+    return ctx.program.methods
+      .finalizeGameweek(gameweek)
+      .accountsPartial({ admin: ctx.admin.publicKey, globalState: pda(ctx.program, "fpl-global") })
+      .rpc();
+  }
+
+  function resolvePlayerMarket(market: PublicKey, playerPoints: PublicKey, otherPlayerPoints: PublicKey | null = null) {
+    return ctx.program.methods
+      .resolvePlayerMarket()
+      .accountsPartial({
+        resolver: ctx.admin.publicKey,
+        market,
+        globalState: pda(ctx.program, "fpl-global"),
+        playerPoints,
+        otherPlayerPoints,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx, { protocolFee });
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+
+    publisher = await newUser(ctx.context);
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+  });
+
+  // Only the FPL admin sets up the oracle and its publisher
+  it('Initializes the oracle', async () => {
+    const outsider = await newUser(ctx.context);
+    const initOracle = (admin: Keypair) =>
+      ctx.program.methods
+        .initializeOracle(publisher.publicKey)
+        .accountsPartial({
+          admin: admin.publicKey,
+          globalState: pda(ctx.program, "fpl-global"),
+          oracleConfig: pda(ctx.program, "oracle-config"),
+        })
+        .signers(admin === ctx.admin ? [] : [admin])
+        .rpc();
+
+    await expectError(initOracle(outsider), 'UnauthorizedAccess');
+    await initOracle(ctx.admin);
+
+    const config = await ctx.program.account.oracleConfig.fetch(pda(ctx.program, "oracle-config"));
+    expect(config.publisher.toString()).to.equal(publisher.publicKey.toString());
+  });
+
+  // Player markets are yes/no questions on the current gameweek
+  it('Creates player markets', async () => {
+    await createFixture(ctx, {}, publisher);
+
+    await expectError(
+      createMarket(ctx, { playerCondition: { pointsAtLeast: { playerId: salah, gameweek: 2, threshold: 6 } } }),
+      'InvalidGameweek'
+    );
+    await expectError(
+      createMarket(ctx, { playerCondition: pointsAtLeast, outcomes: ["Yes", "No", "Maybe"] }),
+      'InvalidMarketParameter'
+    );
+    await expectError(
+      createMarket(ctx, { playerCondition: pointsAtLeast, closeTime: new anchor.BN(SEASON_START + 8 * DAY) }),
+      'InvalidMarketParameter'
+    );
+    await expectError(
+      createMarket(ctx, { playerCondition: { outscores: { playerId: salah, otherPlayerId: salah, gameweek: 1 } } }),
+      'InvalidMarketParameter'
+    );
+
+    pointsMarket = await createMarket(ctx, { question: "Salah 6+ points in GW1?", playerCondition: pointsAtLeast });
+    outscoresMarket = await createMarket(ctx, { question: "Salah outscores Haaland in GW1?", playerCondition: outscores });
+
+    for (const user of [alice, bob]) {
+      await openPosition(ctx, pointsMarket, user);
+    }
+    await placePosition(ctx, pointsMarket, alice, 0, sol(1));
+    await placePosition(ctx, pointsMarket, bob, 1, sol(1));
+  });
+
+  // Only the publisher posts points, and only for gameweeks that have started
+  it('Publishes player points', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(publishPoints(salah, 1, 8, outsider), 'UnauthorizedAccess');
+    await expectError(publishPoints(salah, 2, 8), 'InvalidGameweek');
+
+    await publishPoints(salah, 1, 5);
+    await publishPoints(haaland, 1, 9);
+
+    const points = await ctx.program.account.playerGameweekPoints.fetch(playerPointsPda(salah, 1));
+    expect(points.points).to.equal(5);
+  });
+
+  // Player markets settle from the oracle, not the proposal flow, and only once points are final
+  it('Waits for the gameweek to be finalized', async () => {
+    await warp(ctx.context, DAY);
+
+    await expectError(proposeResolution(ctx, pointsMarket, 0), 'WrongResolutionPath');
+    await expectError(resolvePlayerMarket(pointsMarket, playerPointsPda(salah, 1)), 'GameweekNotFinalized');
+
+    // Bonus points land before the gameweek is finalized
+    await correctPoints(salah, 1, 8);
+    await finalizeGameweek(1);
+    await expectError(correctPoints(salah, 1, 10), 'GameweekFinalized');
+  });
+
+  // Anyone can resolve a player market with the finalized points
+  it('Resolves a points threshold market', async () => {
+    await expectError(resolvePlayerMarket(pointsMarket, playerPointsPda(haaland, 1)), 'PlayerPointsRequired');
+    await resolvePlayerMarket(pointsMarket, playerPointsPda(salah, 1));
+
+    const account = await ctx.program.account.market.fetch(pointsMarket);
+    expect(account.status).to.deep.equal({ resolved: {} });
+    expect(account.winningOutcome).to.equal(0);
+
+    await warp(ctx.context, 0);
+    await expectError(resolvePlayerMarket(pointsMarket, playerPointsPda(salah, 1)), 'MarketAlreadyResolved');
+
+    const before = await balance(ctx.context, alice.publicKey);
+    await claimWinnings(ctx, pointsMarket, alice);
+    expect((await balance(ctx.context, alice.publicKey)) - before).to.equal(sol(1.9).toNumber());
+    await expectError(claimWinnings(ctx, pointsMarket, bob), 'NoWinnings');
+  });
+
+  // Head to head markets need both players' points
+  it('Resolves an outscores market', async () => {
+    await expectError(resolvePlayerMarket(outscoresMarket, playerPointsPda(salah, 1)), 'PlayerPointsRequired');
+    await resolvePlayerMarket(outscoresMarket, playerPointsPda(salah, 1), playerPointsPda(haaland, 1));
+
+    // 8 points doesn't beat Haaland's 9
+    expect((await ctx.program.account.market.fetch(outscoresMarket)).winningOutcome).to.equal(1);
+  });
+
+  // Regular markets can't be settled from oracle points
+  it('Rejects oracle resolution of a regular market', async () => {
+    const market = await createMarket(ctx);
+    await expectError(resolvePlayerMarket(market, playerPointsPda(salah, 1)), 'WrongResolutionPath');
+  });
+});
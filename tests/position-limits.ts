@@ -0,0 +1,220 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  depositToTreasury,
+  registerUser,
+  createMarket,
+  openPosition,
+  placePosition,
+  positionPda,
+  createTokenAccount,
+  tokenBalance,
+  expectError,
+  now,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('position limits', () => {
+  let ctx: Ctx;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+
+  function limits(overrides: Partial<Record<string, unknown>> = {}) {
+    return {
+      maxPosition: new anchor.BN(0),
+      maxPoolShareBps: 0,
+      shareCapMinPool: new anchor.BN(0),
+      minRegistrationAge: new anchor.BN(0),
+      ...overrides,
+    };
+  }
+
+  function buyShares(market: PublicKey, user: Keypair, outcome: number, amount: anchor.BN) {
+    return ctx.program.methods
+      .buyShares(outcome, amount, amount.muln(2))
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        position: positionPda(ctx, market, user.publicKey),
+        fplUser: null,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function mintCompleteSet(market: PublicKey, user: Keypair, tokenAccounts: PublicKey[], amount: anchor.BN) {
+    const mints = [0, 1].map((outcome) => pda(ctx.program, "outcome-mint", market, Buffer.from([outcome])));
+    return ctx.program.methods
+      .mintCompleteSet(amount)
+      .accountsPartial({
+        user: user.publicKey,
+        market,
+        fplUser: null,
+        marketVault: pda(ctx.program, "market-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .remainingAccounts(
+        mints.flatMap((mint, outcome) => [
+          { pubkey: mint, isSigner: false, isWritable: true },
+          { pubkey: tokenAccounts[outcome], isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([user])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+    await depositToTreasury(ctx, sol(10));
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+    carol = await newUser(ctx.context);
+    await registerUser(ctx, alice);
+  });
+
+  // Caps are validated when the market is created
+  it('Rejects invalid limits', async () => {
+    await expectError(createMarket(ctx, { limits: limits({ maxPoolShareBps: 10_001 }) }), 'InvalidMarketParameter');
+    await expectError(createMarket(ctx, { limits: limits({ minRegistrationAge: new anchor.BN(-1) }) }), 'InvalidMarketParameter');
+  });
+
+  // A wallet's total across every outcome is capped
+  it('Caps the collateral per wallet', async () => {
+    const market = await createMarket(ctx, { limits: limits({ maxPosition: sol(2) }) });
+    await openPosition(ctx, market, alice);
+
+    await placePosition(ctx, market, alice, 0, sol(1.5));
+    await expectError(placePosition(ctx, market, alice, 1, sol(0.6)), 'PositionLimitExceeded');
+    await placePosition(ctx, market, alice, 1, sol(0.5));
+
+    const position = await ctx.program.account.position.fetch(positionPda(ctx, market, alice.publicKey));
+    expect(position.amounts.map((amount) => amount.toString())).to.deep.equal([sol(1.5).toString(), sol(0.5).toString()]);
+  });
+
+  // Once an outcome pool is big enough, no wallet may hold more than its share of it
+  it('Caps the share of an outcome pool', async () => {
+    const market = await createMarket(ctx, {
+      limits: limits({ maxPoolShareBps: 5_000, shareCapMinPool: sol(2) }),
+    });
+    for (const user of [alice, bob, carol]) {
+      await openPosition(ctx, market, user);
+    }
+
+    // Below the minimum pool size the share cap doesn't apply
+    await placePosition(ctx, market, alice, 0, sol(1.5));
+    await placePosition(ctx, market, bob, 0, sol(1));
+
+    // 2 of 3 SOL would be two thirds of the pool
+    await expectError(placePosition(ctx, market, alice, 0, sol(0.5)), 'PoolShareExceeded');
+
+    // 2 of 4 SOL is exactly half
+    await placePosition(ctx, market, carol, 0, sol(1));
+    await placePosition(ctx, market, alice, 0, sol(0.5));
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.outcomePools[0].toString()).to.equal(sol(4).toString());
+  });
+
+  // Markets can require wallets to have been registered for a while
+  it('Requires a minimum registration age', async () => {
+    const market = await createMarket(ctx, {
+      closeTime: new anchor.BN((await now(ctx.context)) + 7 * DAY),
+      limits: limits({ minRegistrationAge: new anchor.BN(3 * DAY) }),
+    });
+    for (const user of [alice, bob]) {
+      await openPosition(ctx, market, user);
+    }
+
+    await expectError(placePosition(ctx, market, alice, 0, sol(1), true), 'RegistrationTooRecent');
+    await expectError(placePosition(ctx, market, bob, 0, sol(1)), 'RegistrationTooRecent');
+
+    await warp(ctx.context, 3 * DAY);
+    await placePosition(ctx, market, alice, 0, sol(1), true);
+
+    // Without the FPL account there's nothing to check the age against
+    await expectError(placePosition(ctx, market, alice, 0, sol(1)), 'RegistrationTooRecent');
+  });
+
+  // LMSR trades count the collateral paid in towards the wallet cap
+  it('Caps LMSR trades', async () => {
+    const market = await createMarket(ctx, { mechanism: { lmsr: {} }, limits: limits({ maxPosition: sol(1) }) });
+    await ctx.program.methods
+      .fundMarket(sol(1))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        market,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+    await openPosition(ctx, market, alice);
+
+    // About 0.62 SOL for the first b shares, 0.81 SOL for the next
+    await buyShares(market, alice, 0, sol(1));
+    await expectError(buyShares(market, alice, 0, sol(1)), 'PositionLimitExceeded');
+
+    const position = await ctx.program.account.position.fetch(positionPda(ctx, market, alice.publicKey));
+    expect(position.shares[0].toString()).to.equal(sol(1).toString());
+  });
+
+  // Complete sets count every outcome token the wallet holds against the supply
+  it('Caps complete set mints', async () => {
+    const market = await createMarket(ctx, {
+      mechanism: { outcomeTokens: {} },
+      limits: limits({ maxPoolShareBps: 5_000, shareCapMinPool: sol(2) }),
+    });
+    const tokenAccounts = new Map<Keypair, PublicKey[]>();
+    for (const outcome of [0, 1]) {
+      await ctx.program.methods
+        .initializeOutcomeMint(outcome)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          marketConfig: pda(ctx.program, "market-config"),
+          market,
+          outcomeMint: pda(ctx.program, "outcome-mint", market, Buffer.from([outcome])),
+        })
+        .rpc();
+    }
+    for (const user of [alice, bob]) {
+      const mints = [0, 1].map((outcome) => pda(ctx.program, "outcome-mint", market, Buffer.from([outcome])));
+      tokenAccounts.set(user, await Promise.all(mints.map((mint) => createTokenAccount(ctx, mint, user.publicKey))));
+    }
+
+    // Tokens can only be minted to the wallet paying for them
+    await expectError(mintCompleteSet(market, alice, tokenAccounts.get(bob), sol(1)), 'UnauthorizedAccess');
+
+    await mintCompleteSet(market, alice, tokenAccounts.get(alice), sol(1.5));
+    await mintCompleteSet(market, bob, tokenAccounts.get(bob), sol(1));
+
+    // 2 of 3 SOL of supply would be two thirds of every outcome
+    await expectError(mintCompleteSet(market, alice, tokenAccounts.get(alice), sol(0.5)), 'PoolShareExceeded');
+    expect(await tokenBalance(ctx, tokenAccounts.get(alice)[0])).to.equal(sol(1.5).toNumber());
+  });
+});
@@ -0,0 +1,163 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initStakeConfig,
+  initRewards,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  claimRewards,
+  queueAction,
+  executeAction,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('protocol fee', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+  let cranker: Keypair;
+  let stakeId: number;
+
+  const protocolFee = 10;
+  const tipBps = 100; // 1%
+
+  function compound() {
+    return ctx.program.methods
+      .compound(new anchor.BN(stakeId))
+      .accountsPartial({
+        cranker: cranker.publicKey,
+        stake: stakePda(ctx, user.publicKey, stakeId),
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+        rewardConfig: pda(ctx.program, "reward-config"),
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        referralStats: null,
+        stakeVault: pda(ctx.program, "stake-vault"),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([cranker])
+      .rpc();
+  }
+
+  async function distributed(): Promise<number> {
+    const pool = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+    return pool.distributedRewards.toNumber();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx, { compoundTipBps: tipBps });
+
+    user = await newUser(ctx.context, 200);
+    cranker = await newUser(ctx.context);
+  });
+
+  // The fee is a percentage and validated like the other treasury settings
+  it('Rejects a protocol fee over 100%', async () => {
+    await expectError(initTreasury(ctx, { protocolFee: 101 }), 'InvalidTreasuryParameter');
+
+    await initTreasury(ctx, { protocolFee, rewardsShare: 50 });
+    await initProtocol(ctx);
+    await initTimelock(ctx);
+
+    await registerUser(ctx, user);
+    stakeId = await stake(ctx, user, sol(100));
+  });
+
+  // Claims pay the user the net reward and the fee to the treasury vault
+  it('Takes the protocol fee out of claimed rewards', async () => {
+    await warp(ctx.context, 10 * DAY);
+
+    const userBefore = await balance(ctx.context, user.publicKey);
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const distributedBefore = await distributed();
+
+    await claimRewards(ctx, user, stakeId);
+
+    const gross = (await distributed()) - distributedBefore;
+    const fee = Math.floor((gross * protocolFee) / 100);
+    expect(fee).to.be.greaterThan(0);
+
+    expect((await balance(ctx.context, user.publicKey)) - userBefore).to.equal(gross - fee);
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - vaultBefore).to.equal(fee);
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toNumber()).to.equal(fee);
+    expect(treasury.pendingRewards.toNumber()).to.equal(Math.floor(fee / 2));
+  });
+
+  // Compounding takes the fee first and the cranker tip out of what's left
+  it('Takes the protocol fee out of compounded rewards', async () => {
+    await ctx.program.methods
+      .setAutoCompound(new anchor.BN(stakeId), true)
+      .accountsPartial({ user: user.publicKey, stake: stakePda(ctx, user.publicKey, stakeId) })
+      .signers([user])
+      .rpc();
+    await warp(ctx.context, 10 * DAY);
+
+    const stakeBefore = await ctx.program.account.stake.fetch(stakePda(ctx, user.publicKey, stakeId));
+    const crankerBefore = await balance(ctx.context, cranker.publicKey);
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const distributedBefore = await distributed();
+
+    await compound();
+
+    const gross = (await distributed()) - distributedBefore;
+    const fee = Math.floor((gross * protocolFee) / 100);
+    const tip = Math.floor(((gross - fee) * tipBps) / 10_000);
+
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - vaultBefore).to.equal(fee);
+    expect((await balance(ctx.context, cranker.publicKey)) - crankerBefore).to.equal(tip);
+
+    const stakeAfter = await ctx.program.account.stake.fetch(stakePda(ctx, user.publicKey, stakeId));
+    expect(stakeAfter.amount.sub(stakeBefore.amount).toNumber()).to.equal(gross - fee - tip);
+  });
+
+  // Turning the fee off through the timelock pays out the full reward
+  it('Pays the gross reward once the fee is zero', async () => {
+    const id = await queueAction(ctx, {
+      updateTreasuryConfig: {
+        update: {
+          newProtocolFee: 0,
+          newReservePercentage: null,
+          newRewardsShare: null,
+          newOutflowCap: null,
+          newOutflowCapPercentage: null,
+          newOutflowWindow: null,
+        },
+      },
+    });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, id);
+    expect((await fetchTreasury(ctx)).protocolFee).to.equal(0);
+
+    const userBefore = await balance(ctx.context, user.publicKey);
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const distributedBefore = await distributed();
+
+    await claimRewards(ctx, user, stakeId);
+
+    const gross = (await distributed()) - distributedBefore;
+    expect((await balance(ctx.context, user.publicKey)) - userBefore).to.equal(gross);
+    expect(await balance(ctx.context, pda(ctx.program, "treasury-vault"))).to.equal(vaultBefore);
+  });
+});
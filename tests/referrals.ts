@@ -0,0 +1,204 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  claimRewards,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('referrals', () => {
+  let ctx: Ctx;
+  let referrer: Keypair;
+  let referee: Keypair;
+  let refereeStake: number;
+
+  const referralShare = 20;
+
+  function initReferralStats(user: Keypair) {
+    return ctx.program.methods
+      .initializeReferralStats()
+      .accountsPartial({
+        referrer: user.publicKey,
+        referralStats: pda(ctx.program, "referral", user.publicKey),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function register(user: Keypair, referrerKey: PublicKey, referralStats: PublicKey | null) {
+    return ctx.program.methods
+      .userreg("7654321", referrerKey)
+      .accountsPartial({
+        user: user.publicKey,
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+        referralStats,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function claimReferralRewards(user: Keypair) {
+    return ctx.program.methods
+      .claimReferralRewards()
+      .accountsPartial({
+        referrer: user.publicKey,
+        referralStats: pda(ctx.program, "referral", user.publicKey),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function compound(referralStats: PublicKey | null) {
+    return ctx.program.methods
+      .compound(new anchor.BN(refereeStake))
+      .accountsPartial({
+        cranker: ctx.admin.publicKey,
+        stake: stakePda(ctx, referee.publicKey, refereeStake),
+        fplUser: pda(ctx.program, "fpl-user", referee.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+        rewardConfig: pda(ctx.program, "reward-config"),
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        referralStats,
+        stakeVault: pda(ctx.program, "stake-vault"),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, { referralShare });
+
+    referrer = await newUser(ctx.context);
+    referee = await newUser(ctx.context);
+    await initReferralStats(referrer);
+  });
+
+  // Referrers opt in with a stats account
+  it('Requires the referrer to have a stats account', async () => {
+    const stranger = await newUser(ctx.context);
+    const user = await newUser(ctx.context);
+
+    await expectError(register(user, stranger.publicKey, null), 'ReferralStatsRequired');
+  });
+
+  // The stats account has to belong to the named referrer
+  it('Rejects a stats account for a different referrer', async () => {
+    const stranger = await newUser(ctx.context);
+    const user = await newUser(ctx.context);
+
+    await expectError(
+      register(user, stranger.publicKey, pda(ctx.program, "referral", referrer.publicKey)),
+      'InvalidReferrer'
+    );
+  });
+
+  // Users can't refer themselves
+  it('Rejects self-referral', async () => {
+    const user = await newUser(ctx.context);
+    await initReferralStats(user);
+
+    await expectError(
+      register(user, user.publicKey, pda(ctx.program, "referral", user.publicKey)),
+      'InvalidReferrer'
+    );
+  });
+
+  // Attribution is recorded once at registration
+  it('Attributes a referee at registration', async () => {
+    await registerUser(ctx, referee, referrer.publicKey);
+
+    const fplUser = await ctx.program.account.fplUser.fetch(pda(ctx.program, "fpl-user", referee.publicKey));
+    expect(fplUser.referrer.toString()).to.equal(referrer.publicKey.toString());
+
+    const stats = await ctx.program.account.referralStats.fetch(pda(ctx.program, "referral", referrer.publicKey));
+    expect(stats.refereeCount).to.equal(1);
+    expect(stats.totalEarned.toNumber()).to.equal(0);
+  });
+
+  // Claims by a referred user have to credit the referrer
+  it('Requires the referral stats when a referee claims', async () => {
+    refereeStake = await stake(ctx, referee, sol(100));
+    await warp(ctx.context, 10 * DAY);
+
+    await expectError(claimRewards(ctx, referee, refereeStake), 'ReferralStatsRequired');
+  });
+
+  // The referrer's share comes from the pool on top of the referee's reward
+  it("Credits the referrer's share when the referee claims", async () => {
+    const poolBefore = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+    const before = await balance(ctx.context, referee.publicKey);
+
+    await claimRewards(ctx, referee, refereeStake, { referrer: referrer.publicKey });
+
+    const reward = (await balance(ctx.context, referee.publicKey)) - before;
+    const stats = await ctx.program.account.referralStats.fetch(pda(ctx.program, "referral", referrer.publicKey));
+    expect(stats.totalEarned.toNumber()).to.equal(Math.floor((reward * referralShare) / 100));
+
+    const poolAfter = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+    expect(poolAfter.distributedRewards.sub(poolBefore.distributedRewards).toNumber()).to.equal(
+      reward + stats.totalEarned.toNumber()
+    );
+  });
+
+  // Referral rewards are paid out once
+  it('Pays out referral rewards to the referrer', async () => {
+    const stats = await ctx.program.account.referralStats.fetch(pda(ctx.program, "referral", referrer.publicKey));
+    const before = await balance(ctx.context, referrer.publicKey);
+
+    await claimReferralRewards(referrer);
+
+    expect((await balance(ctx.context, referrer.publicKey)) - before).to.equal(stats.totalEarned.toNumber());
+
+    const after = await ctx.program.account.referralStats.fetch(pda(ctx.program, "referral", referrer.publicKey));
+    expect(after.totalClaimed.toNumber()).to.equal(after.totalEarned.toNumber());
+
+    await warp(ctx.context, 0);
+    await expectError(claimReferralRewards(referrer), 'NoRewardsAvailable');
+  });
+
+  // Compounded rewards credit the referrer like a manual claim
+  it("Credits the referrer's share when the referee compounds", async () => {
+    await ctx.program.methods
+      .setAutoCompound(new anchor.BN(refereeStake), true)
+      .accountsPartial({ user: referee.publicKey, stake: stakePda(ctx, referee.publicKey, refereeStake) })
+      .signers([referee])
+      .rpc();
+    await warp(ctx.context, 10 * DAY);
+
+    await expectError(compound(null), 'ReferralStatsRequired');
+
+    const statsPda = pda(ctx.program, "referral", referrer.publicKey);
+    const statsBefore = await ctx.program.account.referralStats.fetch(statsPda);
+    const stakeBefore = await ctx.program.account.stake.fetch(stakePda(ctx, referee.publicKey, refereeStake));
+
+    await compound(statsPda);
+
+    const stakeAfter = await ctx.program.account.stake.fetch(stakePda(ctx, referee.publicKey, refereeStake));
+    const compounded = stakeAfter.amount.sub(stakeBefore.amount).toNumber();
+    expect(compounded).to.be.greaterThan(0);
+
+    const statsAfter = await ctx.program.account.referralStats.fetch(statsPda);
+    expect(statsAfter.totalEarned.sub(statsBefore.totalEarned).toNumber()).to.equal(
+      Math.floor((compounded * referralShare) / 100)
+    );
+  });
+});
@@ -0,0 +1,203 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  SEASON_START,
+  setup,
+  initStakingProtocol,
+  newUser,
+  registerUser,
+  stake,
+  unstake,
+  claimRewards,
+  expectedReward,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('participation streaks', () => {
+  let ctx: Ctx;
+  let early: Keypair;
+  let late: Keypair;
+  let earlyStake: number;
+
+  const stakeAmount = sol(100);
+
+  function advanceGameweek(gameweek: number, deadline: number, admin: Keypair = ctx.admin) {
+    return ctx.program.methods
+      .advanceGameweek(gameweek, new anchor.BN(deadline))
+      .accountsPartial({ admin: admin.publicKey, globalState: pda(ctx.program, "fpl-global") })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  function finalizeGameweek(gameweek: number) {
+    return ctx.program.methods
+      .finalizeGameweek(gameweek)
+      .accountsPartial({ admin: ctx.admin.publicKey, globalState: pda(ctx.program, "fpl-global") })
+      .rpc();
+  }
+
+  function recordScore(user: Keypair, gameweek: number, score = 50, admin: Keypair = ctx.admin) {
+    return ctx.program.methods
+      .recordGameweekScore(gameweek, score)
+      .accountsPartial({
+        admin: admin.publicKey,
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  function updateStreak(user: Keypair) {
+    return ctx.program.methods
+      .updateStreak()
+      .accountsPartial({
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        globalState: pda(ctx.program, "fpl-global"),
+      })
+      .rpc();
+  }
+
+  async function streakOf(user: Keypair) {
+    return ctx.program.account.fplUser.fetch(pda(ctx.program, "fpl-user", user.publicKey));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, { streakBonus: 10, maxStreakBonus: 30 });
+
+    early = await newUser(ctx.context);
+    late = await newUser(ctx.context);
+    await registerUser(ctx, early);
+    await registerUser(ctx, late);
+  });
+
+  // The run starts at the current gameweek, or the next once its deadline has passed
+  it('Starts a staking run at the right gameweek', async () => {
+    earlyStake = await stake(ctx, early, stakeAmount);
+    expect((await streakOf(early)).stakedSinceGameweek).to.equal(1);
+
+    // Gameweek 1's deadline is a week after the season starts
+    await warp(ctx.context, 8 * DAY);
+    await stake(ctx, late, stakeAmount);
+    expect((await streakOf(late)).stakedSinceGameweek).to.equal(2);
+  });
+
+  // Only users staked through a finalized gameweek they entered get a streak
+  it('Counts finalized gameweeks entered during the staking run', async () => {
+    await finalizeGameweek(1);
+    await expectError(updateStreak(early), 'NoGameweekEntry');
+
+    await recordScore(early, 1);
+    await recordScore(late, 1);
+    await updateStreak(early);
+    await expectError(updateStreak(late), 'NoGameweekEntry');
+
+    expect((await streakOf(early)).streak).to.equal(1);
+    expect((await streakOf(late)).streak).to.equal(0);
+  });
+
+  // A finalized gameweek is only counted once
+  it('Rejects updating a streak twice for the same gameweek', async () => {
+    await warp(ctx.context, 0);
+    await expectError(updateStreak(early), 'InvalidGameweek');
+  });
+
+  // Gameweeks can't be finalized ahead of the current one
+  it('Rejects finalizing a gameweek that has not started', async () => {
+    await expectError(finalizeGameweek(2), 'InvalidGameweek');
+  });
+
+  // Gameweeks advance one at a time with increasing deadlines
+  it('Validates gameweek advances', async () => {
+    const intruder = await newUser(ctx.context);
+
+    await expectError(advanceGameweek(3, SEASON_START + 14 * DAY), 'InvalidGameweek');
+    await expectError(advanceGameweek(2, SEASON_START + 7 * DAY), 'InvalidGameweek');
+    await expectError(advanceGameweek(2, SEASON_START + 14 * DAY, intruder), 'UnauthorizedAccess');
+
+    await advanceGameweek(2, SEASON_START + 14 * DAY);
+  });
+
+  // Scores are recorded by the admin, once per started gameweek
+  it('Records one score per gameweek', async () => {
+    const intruder = await newUser(ctx.context);
+
+    await expectError(recordScore(early, 2, 60, intruder), 'UnauthorizedAccess');
+    await expectError(recordScore(early, 1), 'InvalidGameweek');
+    await expectError(recordScore(early, 3), 'InvalidGameweek');
+
+    await recordScore(early, 2, 60);
+    const user = await streakOf(early);
+    expect(user.weeklyScore).to.equal(60);
+    expect(user.totalScore).to.equal(110);
+    expect(user.lastEntryGameweek).to.equal(2);
+  });
+
+  // Streaks keep growing and boost rewards up to the cap
+  it('Applies the streak bonus to claims', async () => {
+    await warp(ctx.context, 7 * DAY);
+    await finalizeGameweek(2);
+    await recordScore(late, 2);
+    await updateStreak(early);
+    await updateStreak(late);
+
+    expect((await streakOf(early)).streak).to.equal(2);
+    expect((await streakOf(late)).streak).to.equal(1);
+
+    const before = await balance(ctx.context, early.publicKey);
+    await claimRewards(ctx, early, earlyStake);
+    const reward = (await balance(ctx.context, early.publicKey)) - before;
+
+    // Two gameweeks at 10% each
+    const base = expectedReward(stakeAmount.toNumber(), 15 * DAY);
+    expect(reward).to.be.approximately(Math.floor((base * 120) / 100), 2);
+  });
+
+  // Fully unstaking ends the run, a new stake starts a new one
+  it('Resets the streak on a full unstake', async () => {
+    await unstake(ctx, early, earlyStake);
+    expect((await streakOf(early)).streak).to.equal(0);
+
+    // Gameweek 2's deadline has passed, so the new run starts at gameweek 3
+    await stake(ctx, early, stakeAmount);
+    expect((await streakOf(early)).stakedSinceGameweek).to.equal(3);
+
+    await advanceGameweek(3, SEASON_START + 21 * DAY);
+    await warp(ctx.context, 7 * DAY);
+    await finalizeGameweek(3);
+    await recordScore(early, 3);
+    await recordScore(late, 3);
+    await updateStreak(early);
+    await updateStreak(late);
+
+    expect((await streakOf(early)).streak).to.equal(1);
+    expect((await streakOf(late)).streak).to.equal(2);
+  });
+
+  // Missing a gameweek starts the streak again from one
+  it('Restarts the streak after a missed gameweek', async () => {
+    await advanceGameweek(4, SEASON_START + 28 * DAY);
+    await finalizeGameweek(4);
+    await recordScore(early, 4);
+    await updateStreak(early);
+    await expectError(updateStreak(late), 'NoGameweekEntry');
+
+    await advanceGameweek(5, SEASON_START + 35 * DAY);
+    await finalizeGameweek(5);
+    await recordScore(early, 5);
+    await recordScore(late, 5);
+    await updateStreak(early);
+    await updateStreak(late);
+
+    expect((await streakOf(early)).streak).to.equal(3);
+    expect((await streakOf(late)).streak).to.equal(1);
+  });
+});
@@ -0,0 +1,118 @@
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initStakeConfig,
+  initRewards,
+  initTreasury,
+  initProtocol,
+  newUser,
+  registerUser,
+  stake,
+  unstake,
+  depositToTreasury,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('sweep to rewards', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+
+  // Unstaking straight away pays the 10% early withdrawal fee to the treasury
+  async function earlyUnstakeFee(amount: number) {
+    await unstake(ctx, user, await stake(ctx, user, sol(amount * 10)));
+  }
+
+  function sweepToRewards() {
+    return ctx.program.methods
+      .sweepToRewards()
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx, {}, sol(0));
+    await initTreasury(ctx, { rewardsShare: 50, outflowCap: sol(6) });
+    await initProtocol(ctx);
+
+    user = await newUser(ctx.context, 200);
+    await registerUser(ctx, user);
+  });
+
+  // Nothing accrued yet
+  it('Rejects a sweep with nothing pending', async () => {
+    await expectError(sweepToRewards(), 'NoRewardsAvailable');
+  });
+
+  // Incoming fees accrue the configured share for the reward pool
+  it('Accrues the rewards share of incoming fees', async () => {
+    await earlyUnstakeFee(10);
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(sol(10).toString());
+    expect(treasury.pendingRewards.toString()).to.equal(sol(5).toString());
+  });
+
+  // Deposited principal stays on the ledger without feeding the reward pool
+  it('Takes no rewards share of deposits', async () => {
+    await depositToTreasury(ctx, sol(4));
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(sol(14).toString());
+    expect(treasury.totalDeposits.toString()).to.equal(sol(4).toString());
+    expect(treasury.pendingRewards.toString()).to.equal(sol(5).toString());
+  });
+
+  // The pending share moves from the treasury ledger and vault to the reward pool
+  it('Sweeps the pending share into the reward pool', async () => {
+    const treasuryVault = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const rewardVault = await balance(ctx.context, pda(ctx.program, "reward-vault"));
+
+    await sweepToRewards();
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(sol(9).toString());
+    expect(treasury.pendingRewards.toNumber()).to.equal(0);
+
+    const pool = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+    expect(pool.totalRewards.toString()).to.equal(sol(5).toString());
+
+    expect(treasuryVault - (await balance(ctx.context, pda(ctx.program, "treasury-vault")))).to.equal(sol(5).toNumber());
+    expect((await balance(ctx.context, pda(ctx.program, "reward-vault"))) - rewardVault).to.equal(sol(5).toNumber());
+
+    await warp(ctx.context, 0);
+    await expectError(sweepToRewards(), 'NoRewardsAvailable');
+  });
+
+  // Sweeps share the rolling outflow cap with withdrawals
+  it('Counts sweeps towards the outflow cap', async () => {
+    await earlyUnstakeFee(4);
+
+    // 5 SOL already went out in this window, 2 more would pass the 6 SOL cap
+    await expectError(sweepToRewards(), 'OutflowLimitExceeded');
+
+    await warp(ctx.context, 2 * DAY);
+    await sweepToRewards();
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.pendingRewards.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,157 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  initTimelock,
+  newUser,
+  depositToTreasury,
+  withdrawFromTreasury,
+  queueAction,
+  executeAction,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  u64,
+  warp,
+  sol,
+} from './helpers';
+
+describe('timelock', () => {
+  let ctx: Ctx;
+
+  function cancelAction(id: number) {
+    return ctx.program.methods
+      .cancelAction()
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        timelock: pda(ctx.program, "timelock"),
+        pendingAction: pda(ctx.program, "pending-action", u64(id)),
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx);
+    await depositToTreasury(ctx, sol(20));
+  });
+
+  // The delay has a floor so queued actions can't be rushed through
+  it('Rejects a delay under a day', async () => {
+    await expectError(initTimelock(ctx, DAY - 1), 'InvalidTimelockParameter');
+    await initTimelock(ctx, DAY, sol(10));
+
+    const timelock = await ctx.program.account.timelock.fetch(pda(ctx.program, "timelock"));
+    expect(timelock.delay.toNumber()).to.equal(DAY);
+    expect(timelock.largeWithdrawalThreshold.toString()).to.equal(sol(10).toString());
+  });
+
+  // Withdrawals at or above the threshold can't skip the queue
+  it('Requires the timelock for large withdrawals', async () => {
+    await expectError(withdrawFromTreasury(ctx, sol(10), ctx.admin.publicKey), 'RequiresTimelock');
+  });
+
+  // Only the timelock admin queues actions
+  it('Rejects actions queued by anyone else', async () => {
+    const outsider = await newUser(ctx.context);
+    const { actionCount } = await ctx.program.account.timelock.fetch(pda(ctx.program, "timelock"));
+
+    await expectError(
+      ctx.program.methods
+        .queueAction({ updateRewardApy: { baseApy: 20 } })
+        .accountsPartial({
+          admin: outsider.publicKey,
+          timelock: pda(ctx.program, "timelock"),
+          pendingAction: pda(ctx.program, "pending-action", u64(actionCount.toNumber())),
+        })
+        .signers([outsider])
+        .rpc(),
+      'UnauthorizedAccess'
+    );
+  });
+
+  // Bad parameters are rejected when queued, not after the delay
+  it('Validates actions when they are queued', async () => {
+    await expectError(queueAction(ctx, { updateRewardApy: { baseApy: 101 } }), 'InvalidRewardParameter');
+    await expectError(queueAction(ctx, { updateStakeFee: { earlyWithdrawalFee: 101 } }), 'InvalidStakeParameter');
+    await expectError(
+      queueAction(ctx, { updateTimelock: { delay: new anchor.BN(DAY - 1), largeWithdrawalThreshold: sol(10) } }),
+      'InvalidTimelockParameter'
+    );
+    await expectError(
+      queueAction(ctx, { withdraw: { amount: sol(0), recipient: ctx.admin.publicKey, mint: null } }),
+      'InvalidWithdrawalAmount'
+    );
+  });
+
+  // A queued withdrawal runs once the delay has passed, and only once
+  it('Executes a queued withdrawal after the delay', async () => {
+    const recipient = Keypair.generate();
+    const id = await queueAction(ctx, { withdraw: { amount: sol(15), recipient: recipient.publicKey, mint: null } });
+
+    const pending = await ctx.program.account.pendingAction.fetch(pda(ctx.program, "pending-action", u64(id)));
+    expect(pending.eta.sub(pending.queuedAt).toNumber()).to.equal(DAY);
+
+    await expectError(executeAction(ctx, id, { recipient: recipient.publicKey }), 'TimelockNotReady');
+
+    await warp(ctx.context, DAY);
+    await expectError(executeAction(ctx, id, { recipient: ctx.admin.publicKey }), 'UnauthorizedAccess');
+
+    await executeAction(ctx, id, { recipient: recipient.publicKey });
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(sol(15).toNumber());
+    expect((await fetchTreasury(ctx)).totalFees.toString()).to.equal(sol(5).toString());
+
+    await warp(ctx.context, 0);
+    await expectError(executeAction(ctx, id, { recipient: recipient.publicKey }), 'ActionAlreadyExecuted');
+  });
+
+  // Parameter changes go through the same queue
+  it('Applies a queued reward APY change', async () => {
+    const id = await queueAction(ctx, { updateRewardApy: { baseApy: 20 } });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, id);
+
+    const config = await ctx.program.account.rewardConfig.fetch(pda(ctx.program, "reward-config"));
+    expect(config.baseApy).to.equal(20);
+  });
+
+  // Cancelling closes the pending action so it can never run
+  it('Cancels a queued action', async () => {
+    const id = await queueAction(ctx, { updateRewardApy: { baseApy: 50 } });
+    await cancelAction(id);
+
+    expect(await ctx.program.account.pendingAction.fetchNullable(pda(ctx.program, "pending-action", u64(id)))).to.be.null;
+
+    await warp(ctx.context, DAY);
+    await expectError(executeAction(ctx, id), 'AccountNotInitialized');
+
+    const config = await ctx.program.account.rewardConfig.fetch(pda(ctx.program, "reward-config"));
+    expect(config.baseApy).to.equal(20);
+  });
+
+  // Actions left past the grace period have to be queued again
+  it('Expires actions after the grace period', async () => {
+    const id = await queueAction(ctx, { updateStakeFee: { earlyWithdrawalFee: 5 } });
+    await warp(ctx.context, DAY + 15 * DAY);
+
+    await expectError(executeAction(ctx, id), 'ActionExpired');
+  });
+
+  // The timelock can reconfigure itself
+  it('Updates its own delay and threshold', async () => {
+    const id = await queueAction(ctx, {
+      updateTimelock: { delay: new anchor.BN(2 * DAY), largeWithdrawalThreshold: sol(20) },
+    });
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, id);
+
+    const timelock = await ctx.program.account.timelock.fetch(pda(ctx.program, "timelock"));
+    expect(timelock.delay.toNumber()).to.equal(2 * DAY);
+    expect(timelock.largeWithdrawalThreshold.toString()).to.equal(sol(20).toString());
+  });
+});
@@ -0,0 +1,109 @@
+import { expect } from 'chai';
+import { Keypair, SystemProgram, Transaction } from '@solana/web3.js';
+import {
+  Ctx,
+  setup,
+  initStakingProtocol,
+  initTimelock,
+  newUser,
+  registerUser,
+  stake,
+  unstake,
+  depositToTreasury,
+  withdrawFromTreasury,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  sol,
+} from './helpers';
+
+describe('treasury ledger', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+
+  function auditTreasury() {
+    return ctx.program.methods
+      .auditTreasury()
+      .accountsPartial({
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+      })
+      .view();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, {}, { rewardsShare: 50 });
+    await initTimelock(ctx);
+
+    user = await newUser(ctx.context);
+    await registerUser(ctx, user);
+  });
+
+  // The ledger starts out matching the vault
+  it('Audits a fresh treasury as balanced', async () => {
+    const audit = await auditTreasury();
+    expect(audit.recordedFees.toNumber()).to.equal(0);
+    expect(audit.vaultBalance.toNumber()).to.equal(audit.rentReserve.toNumber());
+    expect(audit.surplus.toNumber()).to.equal(0);
+  });
+
+  // Early unstake fees land in the treasury vault, not the treasury state account
+  it('Moves early unstake fees into the treasury vault', async () => {
+    const stakeId = await stake(ctx, user, sol(10));
+    const vaultBefore = await balance(ctx.context, pda(ctx.program, "treasury-vault"));
+    const stateBefore = await balance(ctx.context, pda(ctx.program, "treasury"));
+
+    await unstake(ctx, user, stakeId);
+
+    // 10% early withdrawal fee
+    const fee = sol(1).toNumber();
+    expect((await balance(ctx.context, pda(ctx.program, "treasury-vault"))) - vaultBefore).to.equal(fee);
+    expect(await balance(ctx.context, pda(ctx.program, "treasury"))).to.equal(stateBefore);
+
+    const treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toNumber()).to.equal(fee);
+
+    const audit = await auditTreasury();
+    expect(audit.recordedFees.toNumber()).to.equal(fee);
+    expect(audit.vaultBalance.toNumber() - audit.rentReserve.toNumber()).to.equal(fee);
+  });
+
+  // Withdrawals can't dip into the share owed to the reward pool
+  it('Keeps the pending rewards share out of withdrawals', async () => {
+    await depositToTreasury(ctx, sol(9));
+    const recipient = Keypair.generate();
+
+    // 10 SOL recorded, half of the 1 SOL fee is pending for the reward pool
+    let treasury = await fetchTreasury(ctx);
+    expect(treasury.totalDeposits.toString()).to.equal(sol(9).toString());
+    expect(treasury.pendingRewards.toString()).to.equal(sol(0.5).toString());
+
+    await expectError(withdrawFromTreasury(ctx, sol(9.6), recipient.publicKey), 'ExceedsWithdrawalLimit');
+
+    await withdrawFromTreasury(ctx, sol(9.5), recipient.publicKey);
+    expect(await balance(ctx.context, recipient.publicKey)).to.equal(sol(9.5).toNumber());
+
+    treasury = await fetchTreasury(ctx);
+    expect(treasury.totalFees.toString()).to.equal(treasury.pendingRewards.toString());
+
+    expect((await auditTreasury()).surplus.toNumber()).to.equal(0);
+  });
+
+  // Lamports sent straight to the vault are reported as a surplus, not a failure
+  it('Reports lamports that bypass the ledger as a surplus', async () => {
+    const tx = new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: ctx.admin.publicKey,
+        toPubkey: pda(ctx.program, "treasury-vault"),
+        lamports: sol(1).toNumber(),
+      })
+    );
+    await ctx.provider.sendAndConfirm(tx);
+
+    const audit = await auditTreasury();
+    expect(audit.surplus.toNumber()).to.equal(sol(1).toNumber());
+    expect(audit.recordedFees.toString()).to.equal((await fetchTreasury(ctx)).totalFees.toString());
+  });
+});
@@ -0,0 +1,204 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initStakeConfig,
+  initRewards,
+  initTreasury,
+  initProtocol,
+  initTimelock,
+  newUser,
+  queueAction,
+  executeAction,
+  createMint,
+  createTokenAccount,
+  mintTokens,
+  tokenBalance,
+  fetchTreasury,
+  expectError,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('treasury mints', () => {
+  let ctx: Ctx;
+  let mint: PublicKey;
+  let otherMint: PublicKey;
+  let adminTokens: PublicKey;
+  let recipient: Keypair;
+  let recipientTokens: PublicKey;
+
+  function addTreasuryMint(tokenMint: PublicKey, overrides: Record<string, unknown> = {}, admin: Keypair = ctx.admin) {
+    return ctx.program.methods
+      .addTreasuryMint({
+        reservePercentage: 50,
+        largeWithdrawalThreshold: new anchor.BN(500),
+        outflowCap: new anchor.BN(0),
+        outflowCapPercentage: 0,
+        outflowWindow: new anchor.BN(DAY),
+        ...overrides,
+      } as any)
+      .accountsPartial({
+        admin: admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        mint: tokenMint,
+        treasuryMint: pda(ctx.program, "treasury-mint", tokenMint),
+        tokenVault: pda(ctx.program, "treasury-token-vault", tokenMint),
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  // Accounts the SPL paths need for a given mint
+  function tokenAccounts(tokenMint: PublicKey) {
+    return {
+      treasuryMint: pda(ctx.program, "treasury-mint", tokenMint),
+      tokenVault: pda(ctx.program, "treasury-token-vault", tokenMint),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
+  function deposit(amount: number, mintArg: PublicKey = mint, accounts: object = tokenAccounts(mint)) {
+    return ctx.program.methods
+      .treasurydeposit(new anchor.BN(amount), mintArg)
+      .accountsPartial({
+        depositor: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        treasuryMint: null,
+        tokenVault: null,
+        tokenProgram: null,
+        depositorTokenAccount: adminTokens,
+        ...accounts,
+      })
+      .rpc();
+  }
+
+  function withdraw(amount: number, destination: PublicKey = recipientTokens) {
+    return ctx.program.methods
+      .withdraw(new anchor.BN(amount), mint)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        treasury: pda(ctx.program, "treasury"),
+        timelock: pda(ctx.program, "timelock"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        recipient: recipient.publicKey,
+        recipientTokenAccount: destination,
+        protocolState: pda(ctx.program, "protocol-state"),
+        ...tokenAccounts(mint),
+      })
+      .rpc();
+  }
+
+  function fetchTreasuryMint() {
+    return ctx.program.account.treasuryMint.fetch(pda(ctx.program, "treasury-mint", mint));
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initStakeConfig(ctx);
+    await initRewards(ctx, {}, sol(0));
+    await initTreasury(ctx);
+    await initProtocol(ctx);
+    await initTimelock(ctx);
+
+    mint = await createMint(ctx, ctx.admin.publicKey, 6);
+    otherMint = await createMint(ctx, ctx.admin.publicKey, 6);
+    adminTokens = await createTokenAccount(ctx, mint, ctx.admin.publicKey);
+    await mintTokens(ctx, mint, adminTokens, 10_000);
+
+    recipient = Keypair.generate();
+    recipientTokens = await createTokenAccount(ctx, mint, recipient.publicKey);
+  });
+
+  // Each mint gets its own vault owned by the treasury PDA
+  it('Adds a per-mint vault and ledger', async () => {
+    const outsider = await newUser(ctx.context);
+    await expectError(addTreasuryMint(mint, {}, outsider), 'UnauthorizedAccess');
+    await expectError(addTreasuryMint(mint, { reservePercentage: 101 }), 'InvalidTreasuryParameter');
+    await expectError(addTreasuryMint(mint, { outflowCapPercentage: 101 }), 'InvalidTreasuryParameter');
+
+    await addTreasuryMint(mint);
+    await addTreasuryMint(otherMint);
+
+    const treasuryMint = await fetchTreasuryMint();
+    expect(treasuryMint.mint.toString()).to.equal(mint.toString());
+    expect(treasuryMint.vault.toString()).to.equal(pda(ctx.program, "treasury-token-vault", mint).toString());
+    expect(treasuryMint.totalFees.toNumber()).to.equal(0);
+  });
+
+  // Token deposits land in the mint's vault and ledger, not the SOL ledger
+  it('Deposits tokens into the mint vault', async () => {
+    await deposit(1_000);
+
+    expect(await tokenBalance(ctx, pda(ctx.program, "treasury-token-vault", mint))).to.equal(1_000);
+    expect((await fetchTreasuryMint()).totalFees.toNumber()).to.equal(1_000);
+    expect((await fetchTreasury(ctx)).totalFees.toNumber()).to.equal(0);
+  });
+
+  // The accounts have to be present and all belong to the requested mint
+  it('Rejects deposits with missing or mismatched accounts', async () => {
+    await expectError(deposit(100, mint, { treasuryMint: null, tokenVault: null, tokenProgram: null }), 'TokenAccountsRequired');
+    await expectError(deposit(100, otherMint), 'InvalidTreasuryMint');
+    await expectError(deposit(100, mint, tokenAccounts(otherMint)), 'InvalidTreasuryMint');
+  });
+
+  // The mint's own threshold and reserve apply to its withdrawals
+  it('Withdraws tokens within the mint limits', async () => {
+    await expectError(withdraw(500), 'RequiresTimelock');
+
+    await withdraw(400);
+    expect(await tokenBalance(ctx, recipientTokens)).to.equal(400);
+    expect((await fetchTreasuryMint()).totalFees.toNumber()).to.equal(600);
+
+    // Half of the remaining 600 stays in reserve
+    await expectError(withdraw(350), 'ExceedsWithdrawalLimit');
+
+    const wrongMint = await createTokenAccount(ctx, otherMint, recipient.publicKey);
+    await expectError(withdraw(100, wrongMint), 'InvalidTreasuryMint');
+  });
+
+  // Large token withdrawals and per-mint limit changes go through the timelock
+  it('Applies queued token withdrawals and mint limit changes', async () => {
+    await deposit(1_000);
+
+    const withdrawal = await queueAction(ctx, { withdraw: { amount: new anchor.BN(600), recipient: recipient.publicKey, mint } });
+    const update = await queueAction(ctx, {
+      updateTreasuryMint: {
+        mint,
+        update: {
+          newReservePercentage: null,
+          newLargeWithdrawalThreshold: null,
+          newOutflowCap: new anchor.BN(100),
+          newOutflowCapPercentage: null,
+          newOutflowWindow: null,
+        },
+      },
+    });
+
+    await warp(ctx.context, DAY);
+    await executeAction(ctx, withdrawal, {
+      recipient: recipient.publicKey,
+      recipientTokenAccount: recipientTokens,
+      ...tokenAccounts(mint),
+    });
+    expect(await tokenBalance(ctx, recipientTokens)).to.equal(1_000);
+
+    // Let the withdrawal above leave the window before capping it
+    await warp(ctx.context, 2 * DAY);
+    await expectError(executeAction(ctx, update), 'TokenAccountsRequired');
+    await executeAction(ctx, update, { treasuryMint: pda(ctx.program, "treasury-mint", mint) });
+    expect((await fetchTreasuryMint()).outflowLimit.cap.toNumber()).to.equal(100);
+
+    await expectError(withdraw(150), 'OutflowLimitExceeded');
+    await withdraw(100);
+    expect(await tokenBalance(ctx, recipientTokens)).to.equal(1_100);
+  });
+});
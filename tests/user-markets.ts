@@ -0,0 +1,201 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  fixturePda,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  marketParams,
+  marketPda,
+  openPosition,
+  placePosition,
+  resolveMarket,
+  claimWinnings,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('user markets', () => {
+  let ctx: Ctx;
+  let creator: Keypair;
+  let smallStaker: Keypair;
+  let alice: Keypair;
+  let bob: Keypair;
+  let creatorStake: number;
+  let smallStake: number;
+  let market: PublicKey;
+
+  const protocolFee = 10;
+  const creatorFeeBps = 200;
+
+  async function createUserMarket(
+    user: Keypair,
+    stakeAccount: PublicKey,
+    overrides: Partial<Record<string, unknown>> = {},
+    feeBps = creatorFeeBps
+  ): Promise<PublicKey> {
+    const { marketCount } = await ctx.program.account.marketConfig.fetch(pda(ctx.program, "market-config"));
+    const market = marketPda(ctx, marketCount.toNumber());
+    const params = await marketParams(ctx, overrides);
+
+    await ctx.program.methods
+      .createUserMarket(params as any, feeBps)
+      .accountsPartial({
+        creator: user.publicKey,
+        fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+        stake: stakeAccount,
+        marketConfig: pda(ctx.program, "market-config"),
+        market,
+        marketVault: pda(ctx.program, "market-vault", market),
+        globalState: pda(ctx.program, "fpl-global"),
+        fixture: fixturePda(ctx, (params.fixtureId as anchor.BN).toNumber()),
+        bondVault: pda(ctx.program, "bond-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+
+    return market;
+  }
+
+  function creatorClaim(method: 'claimCreatorFees' | 'reclaimCreationBond', market: PublicKey, user: Keypair = creator) {
+    return ctx.program.methods[method]()
+      .accountsPartial({
+        creator: user.publicKey,
+        market,
+        marketVault: pda(ctx.program, "market-vault", market),
+        bondVault: pda(ctx.program, "bond-vault", market),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function received(user: Keypair, action: () => Promise<unknown>): Promise<number> {
+    const before = await balance(ctx.context, user.publicKey);
+    await action();
+    return (await balance(ctx.context, user.publicKey)) - before;
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, {}, { protocolFee });
+    await initMarketConfig(ctx);
+    await initOracle(ctx);
+    await createFixture(ctx);
+
+    creator = await newUser(ctx.context);
+    smallStaker = await newUser(ctx.context);
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+
+    await registerUser(ctx, creator);
+    await registerUser(ctx, smallStaker);
+    creatorStake = await stake(ctx, creator, sol(10));
+    smallStake = await stake(ctx, smallStaker, sol(5));
+  });
+
+  // Creators need a big enough stake of their own and can only open parimutuel markets
+  it('Rejects creators without the stake or with bad params', async () => {
+    const ownStake = stakePda(ctx, creator.publicKey, creatorStake);
+
+    await expectError(
+      createUserMarket(smallStaker, stakePda(ctx, smallStaker.publicKey, smallStake)),
+      'InsufficientCreatorStake'
+    );
+    await expectError(createUserMarket(smallStaker, ownStake), 'UnauthorizedAccess');
+    await expectError(createUserMarket(creator, ownStake, { mechanism: { lmsr: {} } }), 'WrongMarketMechanism');
+    await expectError(createUserMarket(creator, ownStake, {}, 501), 'InvalidMarketParameter');
+  });
+
+  // The creation bond is held in the market's bond vault
+  it('Creates a user market', async () => {
+    market = await createUserMarket(creator, stakePda(ctx, creator.publicKey, creatorStake));
+
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.creator.toString()).to.equal(creator.publicKey.toString());
+    expect(account.creatorFeeBps).to.equal(creatorFeeBps);
+    expect(account.creationBond.toString()).to.equal(sol(1).toString());
+    expect(await balance(ctx.context, pda(ctx.program, "bond-vault", market))).to.equal(sol(1).toNumber());
+
+    await expectError(creatorClaim('reclaimCreationBond', market), 'MarketNotResolved');
+  });
+
+  // The creator's cut comes out of the losing pool alongside the protocol fee
+  it('Pays the creator fee on settlement', async () => {
+    for (const user of [alice, bob]) {
+      await openPosition(ctx, market, user);
+    }
+    await placePosition(ctx, market, alice, 0, sol(3));
+    await placePosition(ctx, market, bob, 1, sol(1));
+
+    await warp(ctx.context, DAY);
+    await resolveMarket(ctx, market, 0);
+
+    const creatorFee = sol(0.02).toNumber();
+    const account = await ctx.program.account.market.fetch(market);
+    expect(account.feeAmount.toString()).to.equal(sol(0.1).toString());
+    expect(account.creatorFeesOwed.toNumber()).to.equal(creatorFee);
+    expect(account.distributable.toString()).to.equal(sol(0.88).toString());
+
+    expect(await received(alice, () => claimWinnings(ctx, market, alice))).to.equal(sol(3.88).toNumber());
+
+    await expectError(creatorClaim('claimCreatorFees', market, alice), 'UnauthorizedAccess');
+    expect(await received(creator, () => creatorClaim('claimCreatorFees', market))).to.equal(creatorFee);
+
+    await warp(ctx.context, 0);
+    await expectError(creatorClaim('claimCreatorFees', market), 'NoWinnings');
+  });
+
+  // The bond comes back once the market has settled
+  it('Returns the creation bond after settlement', async () => {
+    expect(await received(creator, () => creatorClaim('reclaimCreationBond', market))).to.equal(sol(1).toNumber());
+
+    await warp(ctx.context, 0);
+    await expectError(creatorClaim('reclaimCreationBond', market), 'NoWinnings');
+  });
+
+  // Delisting voids the market and slashes the bond to the treasury
+  it('Slashes the bond of a delisted market', async () => {
+    const delisted = await createUserMarket(creator, stakePda(ctx, creator.publicKey, creatorStake));
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+
+    await ctx.program.methods
+      .voidMarket(true)
+      .accountsPartial({
+        caller: ctx.admin.publicKey,
+        marketConfig: pda(ctx.program, "market-config"),
+        market: delisted,
+        fixture: null,
+        resolution: pda(ctx.program, "resolution", delisted),
+        proposer: null,
+        disputer: null,
+        bondVault: pda(ctx.program, "bond-vault", delisted),
+        marketVault: pda(ctx.program, "market-vault", delisted),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+
+    const account = await ctx.program.account.market.fetch(delisted);
+    expect(account.status).to.deep.equal({ voided: {} });
+    expect(account.delisted).to.be.true;
+    expect((await fetchTreasury(ctx)).totalFees.toNumber() - feesBefore).to.equal(sol(1).toNumber());
+
+    await expectError(creatorClaim('reclaimCreationBond', delisted), 'NoWinnings');
+  });
+});
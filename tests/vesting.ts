@@ -0,0 +1,180 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initStakingProtocol,
+  newUser,
+  registerUser,
+  stake,
+  stakePda,
+  unstake,
+  claimRewards,
+  expectError,
+  balance,
+  now,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('reward vesting', () => {
+  let ctx: Ctx;
+  let user: Keypair;
+  let stakeId: number;
+  let vestingSchedule: PublicKey;
+
+  const vestingDays = 10;
+
+  function withdrawVested() {
+    return ctx.program.methods
+      .withdrawVested()
+      .accountsPartial({
+        user: user.publicKey,
+        vestingSchedule,
+        rewardVault: pda(ctx.program, "reward-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function exitVesting() {
+    return ctx.program.methods
+      .exitVesting()
+      .accountsPartial({
+        user: user.publicKey,
+        vestingSchedule,
+        rewardPool: pda(ctx.program, "reward-pool"),
+        rewardVault: pda(ctx.program, "reward-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initStakingProtocol(ctx, { vestingEnabled: true, vestingDays });
+
+    user = await newUser(ctx.context);
+    await registerUser(ctx, user);
+    stakeId = await stake(ctx, user, sol(100));
+    vestingSchedule = pda(ctx.program, "vesting", user.publicKey);
+  });
+
+  // Vesting mode needs somewhere to put the claim
+  it('Requires a vesting schedule to claim', async () => {
+    await warp(ctx.context, 30 * DAY);
+    await expectError(claimRewards(ctx, user, stakeId), 'VestingScheduleRequired');
+  });
+
+  // The net reward stays in the vault and starts vesting
+  it('Vests claimed rewards instead of paying them out', async () => {
+    await ctx.program.methods
+      .initializeVestingSchedule()
+      .accountsPartial({ user: user.publicKey, vestingSchedule })
+      .signers([user])
+      .rpc();
+
+    const before = await balance(ctx.context, user.publicKey);
+    await claimRewards(ctx, user, stakeId, { vesting: true });
+
+    expect(await balance(ctx.context, user.publicKey)).to.equal(before);
+
+    const schedule = await ctx.program.account.vestingSchedule.fetch(vestingSchedule);
+    const claimedAt = await now(ctx.context);
+    expect(schedule.totalAmount.toNumber()).to.be.greaterThan(0);
+    expect(schedule.withdrawnAmount.toNumber()).to.equal(0);
+    expect(schedule.startTime.toNumber()).to.equal(claimedAt);
+    expect(schedule.endTime.toNumber()).to.equal(claimedAt + vestingDays * DAY);
+  });
+
+  // Nothing has unlocked at the start of the schedule
+  it('Rejects a withdrawal before anything vests', async () => {
+    await expectError(withdrawVested(), 'NothingVested');
+  });
+
+  // Rewards unlock linearly
+  it('Withdraws the linearly vested amount', async () => {
+    await warp(ctx.context, (vestingDays / 2) * DAY);
+
+    const schedule = await ctx.program.account.vestingSchedule.fetch(vestingSchedule);
+    const before = await balance(ctx.context, user.publicKey);
+    await withdrawVested();
+
+    const half = Math.floor(schedule.totalAmount.toNumber() / 2);
+    expect((await balance(ctx.context, user.publicKey)) - before).to.equal(half);
+
+    const after = await ctx.program.account.vestingSchedule.fetch(vestingSchedule);
+    expect(after.withdrawnAmount.toNumber()).to.equal(half);
+  });
+
+  // Withdrawn rewards can't be withdrawn again
+  it('Rejects a second withdrawal at the same time', async () => {
+    await warp(ctx.context, 0);
+    await expectError(withdrawVested(), 'NothingVested');
+  });
+
+  // Exiting pays out what has vested and forfeits the rest back to the pool
+  it('Forfeits unvested rewards to the pool on exit', async () => {
+    await warp(ctx.context, 2 * DAY);
+
+    const schedule = await ctx.program.account.vestingSchedule.fetch(vestingSchedule);
+    const poolBefore = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+    const userBefore = await balance(ctx.context, user.publicKey);
+    const scheduleRent = await balance(ctx.context, vestingSchedule);
+
+    await exitVesting();
+
+    const total = schedule.totalAmount.toNumber();
+    const vested = Math.floor((total * 7) / vestingDays);
+    const poolAfter = await ctx.program.account.rewardPool.fetch(pda(ctx.program, "reward-pool"));
+
+    expect(poolBefore.distributedRewards.sub(poolAfter.distributedRewards).toNumber()).to.equal(total - vested);
+    expect((await balance(ctx.context, user.publicKey)) - userBefore).to.equal(
+      vested - schedule.withdrawnAmount.toNumber() + scheduleRent
+    );
+    expect(await ctx.context.banksClient.getAccount(vestingSchedule)).to.be.null;
+  });
+
+  // Compounding would move unvested rewards into the principal and out with an unstake
+  it('Rejects compounding while rewards vest', async () => {
+    const stakeAccount = stakePda(ctx, user.publicKey, stakeId);
+    await ctx.program.methods
+      .setAutoCompound(new anchor.BN(stakeId), true)
+      .accountsPartial({ user: user.publicKey, stake: stakeAccount })
+      .signers([user])
+      .rpc();
+    await warp(ctx.context, 30 * DAY);
+
+    await expectError(
+      ctx.program.methods
+        .compound(new anchor.BN(stakeId))
+        .accountsPartial({
+          cranker: user.publicKey,
+          stake: stakeAccount,
+          fplUser: pda(ctx.program, "fpl-user", user.publicKey),
+          globalState: pda(ctx.program, "fpl-global"),
+          rewardConfig: pda(ctx.program, "reward-config"),
+          rewardPool: pda(ctx.program, "reward-pool"),
+          rewardVault: pda(ctx.program, "reward-vault"),
+          referralStats: null,
+          stakeVault: pda(ctx.program, "stake-vault"),
+          treasury: pda(ctx.program, "treasury"),
+          treasuryVault: pda(ctx.program, "treasury-vault"),
+          protocolState: pda(ctx.program, "protocol-state"),
+        })
+        .signers([user])
+        .rpc(),
+      'CompoundingWhileVesting'
+    );
+
+    // Unstaking only returns the principal
+    const before = await balance(ctx.context, user.publicKey);
+    await unstake(ctx, user, stakeId);
+    expect((await balance(ctx.context, user.publicKey)) - before).to.equal(sol(100).toNumber());
+  });
+});
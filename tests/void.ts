@@ -0,0 +1,199 @@
+import * as anchor from '@coral-xyz/anchor';
+import { expect } from 'chai';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  Ctx,
+  DAY,
+  setup,
+  initFpl,
+  initTreasury,
+  initProtocol,
+  initMarketConfig,
+  initOracle,
+  createFixture,
+  newUser,
+  depositToTreasury,
+  createMarket,
+  openPosition,
+  placePosition,
+  positionPda,
+  proposeResolution,
+  claimWinnings,
+  fetchTreasury,
+  expectError,
+  balance,
+  pda,
+  warp,
+  sol,
+} from './helpers';
+
+describe('voided markets', () => {
+  let ctx: Ctx;
+  let alice: Keypair;
+  let bob: Keypair;
+  let outsider: Keypair;
+  let markets: PublicKey[];
+
+  const autoVoidDelay = 7 * DAY;
+
+  function voidMarket(
+    market: PublicKey,
+    caller: Keypair = ctx.admin,
+    delist = false,
+    bonds: { proposer?: PublicKey; disputer?: PublicKey } = {}
+  ) {
+    return ctx.program.methods
+      .voidMarket(delist)
+      .accountsPartial({
+        caller: caller.publicKey,
+        marketConfig: pda(ctx.program, "market-config"),
+        market,
+        fixture: null,
+        resolution: pda(ctx.program, "resolution", market),
+        proposer: bonds.proposer ?? null,
+        disputer: bonds.disputer ?? null,
+        bondVault: pda(ctx.program, "bond-vault", market),
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers(caller === ctx.admin ? [] : [caller])
+      .rpc();
+  }
+
+  async function refund(market: PublicKey, user: Keypair): Promise<number> {
+    const before = await balance(ctx.context, user.publicKey);
+    await claimWinnings(ctx, market, user);
+    return (await balance(ctx.context, user.publicKey)) - before;
+  }
+
+  before(async () => {
+    ctx = await setup();
+    await initFpl(ctx);
+    await initTreasury(ctx, { protocolFee: 10 });
+    await initProtocol(ctx);
+    await initMarketConfig(ctx, { autoVoidDelay: new anchor.BN(autoVoidDelay) });
+    await initOracle(ctx);
+    await createFixture(ctx);
+    await depositToTreasury(ctx, sol(10));
+
+    alice = await newUser(ctx.context);
+    bob = await newUser(ctx.context);
+    outsider = await newUser(ctx.context);
+
+    markets = [
+      await createMarket(ctx),
+      await createMarket(ctx, { mechanism: { lmsr: {} } }),
+      await createMarket(ctx),
+      await createMarket(ctx),
+    ];
+  });
+
+  // The admin can void an open market, every position comes back at cost
+  it('Voids a market and refunds positions at cost', async () => {
+    const [market] = markets;
+    for (const user of [alice, bob]) {
+      await openPosition(ctx, market, user);
+    }
+    await placePosition(ctx, market, alice, 0, sol(2));
+    await placePosition(ctx, market, bob, 1, sol(1));
+
+    await expectError(voidMarket(market, outsider), 'UnauthorizedAccess');
+    await expectError(voidMarket(market, outsider, true), 'UnauthorizedAccess');
+
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+    await voidMarket(market);
+    expect((await ctx.program.account.market.fetch(market)).status).to.deep.equal({ voided: {} });
+
+    await warp(ctx.context, 0);
+    await expectError(voidMarket(market), 'MarketAlreadyResolved');
+    await expectError(placePosition(ctx, market, alice, 0, sol(1)), 'MarketClosed');
+
+    // No fee is taken on a voided market
+    expect(await refund(market, alice)).to.equal(sol(2).toNumber());
+    expect(await refund(market, bob)).to.equal(sol(1).toNumber());
+    expect((await fetchTreasury(ctx)).totalFees.toNumber()).to.equal(feesBefore);
+
+    await warp(ctx.context, 0);
+    await expectError(claimWinnings(ctx, market, alice), 'AlreadyClaimed');
+  });
+
+  // LMSR traders get their cost back and the unused subsidy returns to the treasury
+  it('Refunds LMSR positions and returns the subsidy', async () => {
+    const market = markets[1];
+    await ctx.program.methods
+      .fundMarket(sol(1))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        market,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .rpc();
+
+    await openPosition(ctx, market, alice);
+    await ctx.program.methods
+      .buyShares(0, sol(1), sol(2))
+      .accountsPartial({
+        user: alice.publicKey,
+        market,
+        position: positionPda(ctx, market, alice.publicKey),
+        fplUser: null,
+        marketVault: pda(ctx.program, "market-vault", market),
+        treasury: pda(ctx.program, "treasury"),
+        treasuryVault: pda(ctx.program, "treasury-vault"),
+        protocolState: pda(ctx.program, "protocol-state"),
+      })
+      .signers([alice])
+      .rpc();
+
+    const { subsidy, costBasis } = await ctx.program.account.market.fetch(market);
+    const feesBefore = (await fetchTreasury(ctx)).totalFees.toNumber();
+    await voidMarket(market);
+
+    expect((await fetchTreasury(ctx)).totalFees.toNumber() - feesBefore).to.equal(subsidy.toNumber());
+    expect(await refund(market, alice)).to.equal(costBasis.toNumber());
+  });
+
+  // After the auto-void delay anyone can void a market that never resolved
+  it('Auto-voids an unresolved market', async () => {
+    const market = markets[2];
+    await warp(ctx.context, DAY);
+
+    // A pending proposal pushes the auto-void back past its dispute deadline
+    await proposeResolution(ctx, markets[3], 0, sol(1), bob);
+
+    await warp(ctx.context, autoVoidDelay);
+    await expectError(voidMarket(market, outsider), 'UnauthorizedAccess');
+
+    await warp(ctx.context, 1);
+    await voidMarket(market, outsider);
+    expect((await ctx.program.account.market.fetch(market)).status).to.deep.equal({ voided: {} });
+
+    await expectError(voidMarket(markets[3], outsider), 'UnauthorizedAccess');
+  });
+
+  // Voiding a market with a pending proposal gives the proposer's bond back
+  it('Returns pending resolution bonds', async () => {
+    const market = markets[3];
+    await warp(ctx.context, DAY);
+
+    await expectError(voidMarket(market, outsider), 'UnauthorizedAccess');
+    await expectError(
+      voidMarket(market, outsider, false, { proposer: outsider.publicKey }),
+      'UnauthorizedAccess'
+    );
+
+    const before = await balance(ctx.context, bob.publicKey);
+    await voidMarket(market, outsider, false, { proposer: bob.publicKey });
+
+    expect((await balance(ctx.context, bob.publicKey)) - before).to.equal(sol(1).toNumber());
+    expect(await balance(ctx.context, pda(ctx.program, "bond-vault", market))).to.equal(0);
+
+    const resolution = await ctx.program.account.resolutionProposal.fetch(pda(ctx.program, "resolution", market));
+    expect(resolution.status).to.deep.equal({ finalized: {} });
+  });
+});